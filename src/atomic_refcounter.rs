@@ -0,0 +1,195 @@
+use std::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use std::sync::atomic::{self, AtomicUsize};
+
+/// `AtomicRefCounter` is the thread-safe counterpart of
+/// [`RefCounter`](crate::RefCounter), designed for internal use in
+/// [`AtomicUniquePointer`](crate::AtomicUniquePointer).
+///
+/// Clones of an [`AtomicRefCounter`] share the same counter, which
+/// every method updates atomically through a shared reference, such
+/// that clones may be moved to and updated from other threads.
+///
+/// As in [`std::sync::Arc`], increments use relaxed ordering while
+/// decrements release the changes made before them and, once the
+/// count gets down to zero, acquire those of every other decrement,
+/// such that the thread which observes zero can safely free the data
+/// guarded by the counter.
+pub struct AtomicRefCounter {
+    data: *mut AtomicUsize,
+}
+
+unsafe impl Send for AtomicRefCounter {}
+unsafe impl Sync for AtomicRefCounter {}
+
+impl AtomicRefCounter {
+    pub fn null() -> AtomicRefCounter {
+        AtomicRefCounter {
+            data: std::ptr::null_mut::<AtomicUsize>(),
+        }
+    }
+
+    /// `new` allocates a counter starting at one
+    pub fn new() -> AtomicRefCounter {
+        AtomicRefCounter {
+            data: Box::into_raw(Box::new(AtomicUsize::new(1))),
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.data.is_null()
+    }
+
+    pub fn reset(&self) {
+        if let Some(data) = self.data() {
+            data.store(1, atomic::Ordering::Release);
+        }
+    }
+
+    /// `incr` increments the counter and returns the new count
+    pub fn incr(&self) -> usize {
+        self.incr_by(1)
+    }
+
+    pub fn incr_by(&self, by: usize) -> usize {
+        match self.data() {
+            Some(data) => data.fetch_add(by, atomic::Ordering::Relaxed) + by,
+            None => 0,
+        }
+    }
+
+    /// `decr` decrements the counter unless it is zero and returns
+    /// the new count, see [`AtomicRefCounter::decr_by`]
+    pub fn decr(&self) -> usize {
+        self.decr_by(1)
+    }
+
+    /// `decr_by` subtracts `by` from the counter unless the counter
+    /// is lower than `by`, as [`RefCounter::decr_by`](crate::RefCounter::decr_by)
+    /// does, and returns the new count.
+    ///
+    /// A return value of zero means that no other clone of this
+    /// counter holds a reference anymore and that every change made
+    /// by the threads which decremented it is visible to this one.
+    pub fn decr_by(&self, by: usize) -> usize {
+        let Some(data) = self.data() else {
+            return 0;
+        };
+        let previous =
+            data.fetch_update(atomic::Ordering::Release, atomic::Ordering::Relaxed, |refs| {
+                refs.checked_sub(by)
+            });
+        match previous {
+            Ok(previous) if previous == by => {
+                atomic::fence(atomic::Ordering::Acquire);
+                0
+            },
+            Ok(previous) => previous - by,
+            Err(refs) => refs,
+        }
+    }
+
+    pub fn read(&self) -> usize {
+        match self.data() {
+            Some(data) => data.load(atomic::Ordering::Acquire),
+            None => 0,
+        }
+    }
+
+    /// `dealloc` releases the memory of the counter.
+    ///
+    /// Clones of an [`AtomicRefCounter`] share the same memory, so
+    /// only the clone which decremented the counter down to zero
+    /// should call [`dealloc`], after which no other clone may be used.
+    pub fn dealloc(&mut self) {
+        if !self.data.is_null() {
+            unsafe {
+                drop(Box::from_raw(self.data));
+            }
+            self.data = std::ptr::null_mut::<AtomicUsize>();
+        }
+    }
+
+    fn data(&self) -> Option<&AtomicUsize> {
+        unsafe { self.data.as_ref() }
+    }
+}
+
+impl Default for AtomicRefCounter {
+    fn default() -> AtomicRefCounter {
+        AtomicRefCounter::new()
+    }
+}
+
+impl From<usize> for AtomicRefCounter {
+    fn from(refs: usize) -> AtomicRefCounter {
+        AtomicRefCounter {
+            data: Box::into_raw(Box::new(AtomicUsize::new(refs))),
+        }
+    }
+}
+
+impl Drop for AtomicRefCounter {
+    fn drop(&mut self) {
+        // clones share the same counter, see [`AtomicRefCounter::dealloc`]
+    }
+}
+
+impl Clone for AtomicRefCounter {
+    fn clone(&self) -> AtomicRefCounter {
+        AtomicRefCounter { data: self.data }
+    }
+}
+
+impl std::fmt::Debug for AtomicRefCounter {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            crate::color::reset(
+                [
+                    crate::color::fg("AtomicRefCounter@", 231),
+                    format!("{:016x}", self.data.addr()),
+                    format!("[data={}]", self.read()),
+                ]
+                .join("")
+            )
+        )
+    }
+}
+impl std::fmt::Display for AtomicRefCounter {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.read())
+    }
+}
+
+impl PartialOrd<usize> for AtomicRefCounter {
+    fn partial_cmp(&self, other: &usize) -> Option<Ordering> {
+        self.read().partial_cmp(other)
+    }
+}
+
+impl PartialEq<usize> for AtomicRefCounter {
+    fn eq(&self, other: &usize) -> bool {
+        self.read().eq(other)
+    }
+}
+
+impl PartialOrd for AtomicRefCounter {
+    fn partial_cmp(&self, other: &AtomicRefCounter) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AtomicRefCounter {
+    fn cmp(&self, other: &AtomicRefCounter) -> Ordering {
+        self.read().cmp(&other.read())
+    }
+}
+
+impl PartialEq for AtomicRefCounter {
+    fn eq(&self, other: &AtomicRefCounter) -> bool {
+        self.read().eq(&other.read())
+    }
+}
+
+impl Eq for AtomicRefCounter {}
@@ -0,0 +1,238 @@
+use std::fmt::{Debug, Formatter, Pointer};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use crate::AtomicRefCounter;
+
+/// [`AtomicUniquePointer`] is the thread-safe counterpart of
+/// [`UniquePointer`](crate::UniquePointer): a shared pointer whose
+/// clones count their references in an [`AtomicRefCounter`], such
+/// that it is [`Send`] and [`Sync`] whenever `T` is, as
+/// [`std::sync::Arc`] is.
+///
+/// [`AtomicUniquePointer`] offers the surface of
+/// [`UniquePointer`](crate::UniquePointer) (i.e.: [`null`],
+/// [`write`], [`read`], [`inner_ref`], [`as_ref`] and [`dealloc`])
+/// with the differences needed to uphold thread safety:
+///
+/// - [`write`] detaches the pointer from its clones, which keep the
+///   value they shared, rather than overwriting the shared value;
+///
+/// - [`read`] returns a clone of the value rather than a bitwise copy;
+///
+/// - mutable access is only given by [`get_mut`] to a pointer without
+///   clones;
+///
+/// - dropping a pointer releases its reference, as a "soft"
+///   [`dealloc`] does, and the value is dropped and freed along with
+///   the last reference.
+///
+/// Values holding a [`UniquePointer`](crate::UniquePointer) or a
+/// [`Cell`](crate::Cell), such as [`Value`](crate::Value), are not
+/// [`Send`] themselves and so neither are pointers to them.
+///
+/// Example
+///
+/// ```
+/// use ds::AtomicUniquePointer;
+///
+/// let pointer = AtomicUniquePointer::from(String::from("string"));
+/// let clone = pointer.clone();
+/// let thread = std::thread::spawn(move || clone.read());
+///
+/// assert_eq!(thread.join().unwrap(), "string");
+/// assert_eq!(pointer.refs(), 1);
+/// assert_eq!(pointer.inner_ref(), "string");
+/// ```
+pub struct AtomicUniquePointer<T> {
+    mut_ptr: *mut T,
+    refs: AtomicRefCounter,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send + Sync> Send for AtomicUniquePointer<T> {}
+unsafe impl<T: Send + Sync> Sync for AtomicUniquePointer<T> {}
+
+impl<T> AtomicUniquePointer<T> {
+    /// `null` creates a NULL [`AtomicUniquePointer`] ready to be
+    /// written via [`write`].
+    pub fn null() -> AtomicUniquePointer<T> {
+        AtomicUniquePointer {
+            mut_ptr: std::ptr::null_mut::<T>(),
+            refs: AtomicRefCounter::null(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// `addr` returns the memory address of the value
+    pub fn addr(&self) -> usize {
+        self.mut_ptr.addr()
+    }
+
+    /// `refs` returns the number of pointers sharing the value, which
+    /// is zero for a NULL pointer
+    pub fn refs(&self) -> usize {
+        self.refs.read()
+    }
+
+    /// `is_null` returns true if the [`AtomicUniquePointer`] is NULL.
+    pub fn is_null(&self) -> bool {
+        self.mut_ptr.is_null()
+    }
+
+    pub fn is_not_null(&self) -> bool {
+        !self.is_null()
+    }
+
+    /// `is_allocated` returns true if the [`AtomicUniquePointer`]
+    /// points to a value, which is always the case once written
+    pub fn is_allocated(&self) -> bool {
+        self.is_not_null()
+    }
+
+    /// `is_written` returns true if the [`AtomicUniquePointer`] has
+    /// been written to
+    pub fn is_written(&self) -> bool {
+        self.is_not_null()
+    }
+
+    /// `write` releases the value shared with other clones, see
+    /// [`dealloc`], and points this [`AtomicUniquePointer`] to a
+    /// newly allocated `data`.
+    pub fn write(&mut self, data: T) {
+        self.dealloc(true);
+        self.mut_ptr = Box::into_raw(Box::new(data));
+        self.refs = AtomicRefCounter::new();
+    }
+
+    /// `inner_ref` obtains a read-only reference to the value inside
+    /// [`AtomicUniquePointer`], panicking when it is NULL
+    pub fn inner_ref(&self) -> &T {
+        match self.as_ref() {
+            Some(data) => data,
+            None => panic!("{:#?} not written", self),
+        }
+    }
+
+    /// `as_ref` is a compatibility layer to the [`AsRef`] implementation in raw pointers
+    pub fn as_ref(&self) -> Option<&T> {
+        unsafe { self.mut_ptr.as_ref() }
+    }
+
+    /// `get_mut` obtains a mutable reference to the value inside
+    /// [`AtomicUniquePointer`] when no clone shares it
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if self.refs.read() == 1 {
+            unsafe { self.mut_ptr.as_mut() }
+        } else {
+            None
+        }
+    }
+
+    /// `dealloc` deallocates an [`AtomicUniquePointer`], which becomes NULL.
+    ///
+    /// During "soft" deallocation (`soft=true`) the reference count
+    /// is decremented and the value is only dropped and freed when
+    /// this pointer held its last reference.
+    ///
+    /// Conversely "hard" deallocation (`soft=false`) drops and frees
+    /// the value immediately, which requires that no clone shares
+    /// it: other threads might still be reading it, so a hard
+    /// deallocation of a shared value panics rather than causing
+    /// Undefined Behavior.
+    pub fn dealloc(&mut self, soft: bool) {
+        if self.is_null() {
+            return;
+        }
+        if !soft && self.refs.read() > 1 {
+            panic!("{:#?} is shared by {} pointers", self, self.refs.read());
+        }
+        if self.refs.decr() == 0 {
+            unsafe {
+                drop(Box::from_raw(self.mut_ptr));
+            }
+            self.refs.dealloc();
+        }
+        self.mut_ptr = std::ptr::null_mut::<T>();
+        self.refs = AtomicRefCounter::null();
+    }
+}
+
+impl<T: Clone> AtomicUniquePointer<T> {
+    /// `read` returns a clone of the value inside [`AtomicUniquePointer`],
+    /// panicking when it is NULL
+    pub fn read(&self) -> T {
+        self.inner_ref().clone()
+    }
+}
+
+impl<T> Deref for AtomicUniquePointer<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.inner_ref()
+    }
+}
+
+impl<T> Drop for AtomicUniquePointer<T> {
+    fn drop(&mut self) {
+        self.dealloc(true);
+    }
+}
+
+impl<T> From<T> for AtomicUniquePointer<T> {
+    fn from(data: T) -> AtomicUniquePointer<T> {
+        let mut up = AtomicUniquePointer::<T>::null();
+        up.write(data);
+        up
+    }
+}
+
+impl<T> Default for AtomicUniquePointer<T> {
+    fn default() -> AtomicUniquePointer<T> {
+        AtomicUniquePointer::null()
+    }
+}
+
+/// Clones of an [`AtomicUniquePointer`] share its value and reference count.
+impl<T> Clone for AtomicUniquePointer<T> {
+    fn clone(&self) -> AtomicUniquePointer<T> {
+        self.refs.incr();
+        AtomicUniquePointer {
+            mut_ptr: self.mut_ptr,
+            refs: self.refs.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Pointer for AtomicUniquePointer<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{:016x}", self.addr())
+    }
+}
+
+impl<T> Debug for AtomicUniquePointer<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            crate::color::reset(
+                [
+                    crate::color::fg("AtomicUniquePointer@", 237),
+                    format!("{:016x}", self.addr()),
+                    format!("[refs={}]", self.refs),
+                ]
+                .join("")
+            )
+        )
+    }
+}
+
+impl<T: PartialEq> PartialEq for AtomicUniquePointer<T> {
+    fn eq(&self, other: &AtomicUniquePointer<T>) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<T: Eq> Eq for AtomicUniquePointer<T> {}
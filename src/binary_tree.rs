@@ -0,0 +1,267 @@
+use crate::traversal::{
+    InOrder, InOrderMut, LevelOrder, LevelOrderMut, PostOrder, PostOrderMut, PreOrder, PreOrderMut,
+};
+use crate::{cast_node_mut, cast_node_ref, internal, subtree_delete, ListValue, Node, Value};
+
+/// `BinaryTree` owns the root [`Node`] of a tree and frees every
+/// node reachable from it, along with their items and reference
+/// counters, exactly once when dropped.
+///
+/// Nodes linked into a [`BinaryTree`] through [`Node::set_left`] and
+/// [`Node::set_right`] must be allocated via [`BinaryTree::node`]
+/// such that their memory does not depend on the stack frame that
+/// created them.
+///
+/// Example
+///
+/// ```
+/// use ds::{BinaryTree, Value};
+///
+/// let mut tree = BinaryTree::new(Value::from("A"));
+/// let node_b = tree.node(Value::from("B"));
+/// let node_c = tree.node(Value::from("C"));
+///
+/// let root = tree.root_mut().unwrap();
+/// root.set_left(node_b);
+/// root.set_right(node_c);
+///
+/// assert_eq!(tree.len(), 3);
+/// ```
+pub struct BinaryTree<'c, T: ListValue = Value<'c>> {
+    root: *mut Node<'c, T>,
+}
+
+/// `OwnedBinaryTree` is a [`BinaryTree`] of values that borrow
+/// nothing, see [`BinaryTree::into_owned`]
+pub type OwnedBinaryTree = BinaryTree<'static>;
+
+impl<'c> BinaryTree<'c> {
+    /// `nil` creates an empty [`BinaryTree`] of [`Value`], see
+    /// [`BinaryTree::empty`] for other payloads
+    pub fn nil() -> BinaryTree<'c> {
+        BinaryTree::empty()
+    }
+
+    /// `into_owned` copies this tree, in the same shape, into a new
+    /// tree whose values borrow nothing, see [`Node::owned_subtree`]
+    pub fn into_owned(self) -> BinaryTree<'static> {
+        self.root().map(Node::owned_subtree).unwrap_or_default()
+    }
+}
+
+impl<'c, T: ListValue> BinaryTree<'c, T> {
+    /// `empty` creates an empty [`BinaryTree`]
+    pub fn empty() -> BinaryTree<'c, T> {
+        BinaryTree {
+            root: internal::null::node(),
+        }
+    }
+
+    /// `new` creates a [`BinaryTree`] whose root holds `value`
+    pub fn new(value: T) -> BinaryTree<'c, T> {
+        let mut tree = BinaryTree::empty();
+        tree.root = Node::alloc(value);
+        tree
+    }
+
+    /// `node` allocates a detached [`Node`] holding `value` ready to
+    /// be linked into this tree.
+    ///
+    /// A node that never becomes reachable from the root is not
+    /// freed by the tree.
+    pub fn node(&self, value: T) -> &'c mut Node<'c, T> {
+        let node = Node::alloc(value);
+        cast_node_mut!(node, noincr)
+    }
+
+    pub fn root(&self) -> Option<&'c Node<'c, T>> {
+        if self.root.is_null() {
+            None
+        } else {
+            Some(cast_node_ref!(self.root))
+        }
+    }
+
+    pub fn root_mut(&mut self) -> Option<&'c mut Node<'c, T>> {
+        if self.root.is_null() {
+            None
+        } else {
+            Some(cast_node_mut!(self.root, noincr))
+        }
+    }
+
+    /// `len` returns the number of nodes reachable from the root
+    pub fn len(&self) -> usize {
+        self.root().map(Node::size).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_null()
+    }
+
+    /// `subtree_delete` calls [`subtree_delete`](crate::subtree_delete)
+    /// on `node` and frees the leaf it detaches from the tree.
+    pub fn subtree_delete(&mut self, node: &mut Node<'c, T>) {
+        let leaf = subtree_delete(node) as *mut Node<'c, T>;
+        if leaf == self.root {
+            self.root = internal::null::node();
+        }
+        unsafe { Node::free(leaf) }
+    }
+
+    /// `find` returns the node whose item equals `key`, see [`Node::find`]
+    pub fn find(&self, key: &T) -> Option<&'c Node<'c, T>> {
+        self.root().and_then(|root| root.find(key))
+    }
+
+    /// `insert` adds `value` to the tree, see [`Node::insert`]
+    pub fn insert(&mut self, value: T) -> &'c mut Node<'c, T> {
+        if self.root.is_null() {
+            self.root = Node::alloc(value);
+            return cast_node_mut!(self.root, noincr);
+        }
+        self.root_mut().unwrap().insert(value)
+    }
+
+    /// `delete` removes the item equal to `key` from the tree, see [`Node::delete`]
+    pub fn delete(&mut self, key: &T) -> Option<T> {
+        let root = self.root_mut()?;
+        let value = root.delete(key);
+        if root.item.is_null() {
+            unsafe { Node::free(self.root) }
+            self.root = internal::null::node();
+        }
+        value
+    }
+
+    pub fn find_min(&self) -> Option<&'c Node<'c, T>> {
+        self.root().and_then(|root| root.find_min())
+    }
+
+    pub fn find_max(&self) -> Option<&'c Node<'c, T>> {
+        self.root().and_then(|root| root.find_max())
+    }
+
+    pub fn find_next(&self, key: &T) -> Option<&'c Node<'c, T>> {
+        self.root().and_then(|root| root.find_next(key))
+    }
+
+    pub fn find_prev(&self, key: &T) -> Option<&'c Node<'c, T>> {
+        self.root().and_then(|root| root.find_prev(key))
+    }
+
+    /// `subtree_at` returns the node at position `index`, see [`Node::subtree_at`]
+    pub fn subtree_at(&self, index: usize) -> Option<&'c Node<'c, T>> {
+        self.root().and_then(|root| root.subtree_at(index))
+    }
+
+    /// `get_at` returns the item at position `index`, see [`Node::get_at`]
+    pub fn get_at(&self, index: usize) -> Option<T> {
+        self.root().and_then(|root| root.get_at(index))
+    }
+
+    /// `set_at` replaces the item at position `index`, see [`Node::set_at`]
+    pub fn set_at(&mut self, index: usize, value: T) -> Option<T> {
+        self.root_mut().and_then(|root| root.set_at(index, value))
+    }
+
+    /// `insert_at` inserts `value` at position `index`, see [`Node::insert_at`]
+    pub fn insert_at(&mut self, index: usize, value: T) -> &'c mut Node<'c, T> {
+        if self.root.is_null() {
+            assert!(index == 0, "insertion index (is {index}) should be <= size (is 0)");
+            self.root = Node::alloc(value);
+            return cast_node_mut!(self.root, noincr);
+        }
+        self.root_mut().unwrap().insert_at(index, value)
+    }
+
+    /// `delete_at` removes the item at position `index`, see [`Node::delete_at`]
+    pub fn delete_at(&mut self, index: usize) -> Option<T> {
+        let root = self.root_mut()?;
+        let value = root.delete_at(index);
+        if root.item.is_null() {
+            unsafe { Node::free(self.root) }
+            self.root = internal::null::node();
+        }
+        value
+    }
+
+    /// `index_of` returns the position of `node` in the tree, see [`Node::index_of`]
+    pub fn index_of(&self, node: &Node<'c, T>) -> usize {
+        node.index_of()
+    }
+
+    /// `iter_inorder` iterates over the nodes of the tree, see [`Node::iter_inorder`]
+    pub fn iter_inorder(&self) -> InOrder<'c, T> {
+        InOrder::new(self.root)
+    }
+
+    /// `iter_preorder` iterates over the nodes of the tree, see [`Node::iter_preorder`]
+    pub fn iter_preorder(&self) -> PreOrder<'c, T> {
+        PreOrder::new(self.root)
+    }
+
+    /// `iter_postorder` iterates over the nodes of the tree, see [`Node::iter_postorder`]
+    pub fn iter_postorder(&self) -> PostOrder<'c, T> {
+        PostOrder::new(self.root)
+    }
+
+    /// `iter_level_order` iterates over the nodes of the tree, see [`Node::iter_level_order`]
+    pub fn iter_level_order(&self) -> LevelOrder<'c, T> {
+        LevelOrder::new(self.root)
+    }
+
+    /// `iter_inorder_mut` is the mutable variant of [`BinaryTree::iter_inorder`]
+    pub fn iter_inorder_mut(&mut self) -> InOrderMut<'c, T> {
+        InOrderMut::new(self.root)
+    }
+
+    /// `iter_preorder_mut` is the mutable variant of [`BinaryTree::iter_preorder`]
+    pub fn iter_preorder_mut(&mut self) -> PreOrderMut<'c, T> {
+        PreOrderMut::new(self.root)
+    }
+
+    /// `iter_postorder_mut` is the mutable variant of [`BinaryTree::iter_postorder`]
+    pub fn iter_postorder_mut(&mut self) -> PostOrderMut<'c, T> {
+        PostOrderMut::new(self.root)
+    }
+
+    /// `iter_level_order_mut` is the mutable variant of [`BinaryTree::iter_level_order`]
+    pub fn iter_level_order_mut(&mut self) -> LevelOrderMut<'c, T> {
+        LevelOrderMut::new(self.root)
+    }
+}
+
+impl<'c, T: ListValue> Default for BinaryTree<'c, T> {
+    fn default() -> BinaryTree<'c, T> {
+        BinaryTree::empty()
+    }
+}
+
+impl<'c, T: ListValue> Drop for BinaryTree<'c, T> {
+    fn drop(&mut self) {
+        let nodes = self
+            .iter_postorder_mut()
+            .map(|node| node as *mut Node<'c, T>)
+            .collect::<Vec<_>>();
+        for node in nodes {
+            unsafe { Node::free(node) }
+        }
+        self.root = internal::null::node();
+    }
+}
+
+impl<T: ListValue> std::fmt::Debug for BinaryTree<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            crate::color::reset(""),
+            crate::color::fg("BinaryTree", 87),
+            match self.root() {
+                Some(root) => format!("[root:{:#?} | len:{}]", root, self.len()),
+                None => format!("[root:{}]", crate::color::fore("null", 196)),
+            }
+        )
+    }
+}
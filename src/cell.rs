@@ -8,26 +8,46 @@ use std::mem::{ManuallyDrop, MaybeUninit};
 use std::ops::{Deref, DerefMut, Index, IndexMut};
 use std::ptr::NonNull;
 
-use crate::{car, cdr, color, cons, internal, step, Value};
+use crate::{car, cdr, color, cons, internal, step, Symbol, Value};
 
 /// Rust implementation of lisp's cons cell.
+///
+/// A [`Cell`] owns its head and the chain of cells in its tail, all
+/// of which are allocated through [`internal::alloc`]. Cells in a
+/// tail chain count the lists that own them in `refs` and are freed
+/// along with their head when the last of those lists releases them.
+///
+/// Lists share structure only explicitly, through [`Cell::share`],
+/// [`Cell::add`], [`cons`] and [`cdr`], in which case the
+/// [`Cell`] on the stack is a handle to a cell allocated on the heap.
+///
+/// The last cell of an improper list such as `(a . b)` holds the
+/// value that ends the list in `end`, see [`Cell::end`].
 pub struct Cell<'c> {
     head: *mut Value<'c>,
     tail: *mut Cell<'c>,
+    end: *mut Value<'c>,
+    shared: *mut Cell<'c>,
     refs: usize,
 }
 
+/// `OwnedCell` is a [`Cell`] that borrows nothing, see [`Cell::into_owned`]
+pub type OwnedCell = Cell<'static>;
+
 impl<'c> Cell<'c> {
     pub fn nil() -> Cell<'c> {
         Cell {
             head: internal::null::value(),
             tail: internal::null::cell(),
+            end: internal::null::value(),
+            shared: internal::null::cell(),
             refs: 0,
         }
     }
 
     pub fn is_nil(&self) -> bool {
-        self.head.is_null() && self.tail.is_null()
+        let cell = self.cons();
+        cell.head.is_null() && cell.tail.is_null()
     }
 
     pub fn new(value: Value<'c>) -> Cell<'c> {
@@ -40,63 +60,112 @@ impl<'c> Cell<'c> {
         cell
     }
 
+    /// `dotted` returns the dotted pair `(head . end)`, see [`Cell::set_end`]
+    pub fn dotted(head: Value<'c>, end: Value<'c>) -> Cell<'c> {
+        let mut cell = Cell::new(head);
+        cell.set_end(end);
+        cell
+    }
+
     pub fn head(&self) -> Option<Value<'c>> {
-        let value = if self.head.is_null() {
-            None
-        } else {
-            let value = unsafe {
-                let value = self.head.read();
-                value
-            };
-            Some(value)
-        };
-        value
+        unsafe { self.cons().head.as_ref() }.cloned()
     }
 
-    pub fn add(&mut self, mut new: &mut Cell<'c>) {
-        if self.head.is_null() {
-            unsafe {
-                if !new.head.is_null() {
-                    self.head = internal::alloc::value();
-                    std::ptr::swap(self.head as *mut Value<'c>, new.head as *mut Value<'c>);
-                }
+    /// `end` returns the value that ends this list in place of `nil`
+    /// when it is an improper list like `(a b . c)`, or `None` when
+    /// it is a proper list.
+    ///
+    /// The end is not one of the values of the list, hence neither
+    /// [`Cell::iter`] nor [`Cell::len`] count it, and [`cdr`] of the
+    /// last cell is `nil` rather than the end.
+    pub fn end(&self) -> Option<&Value<'c>> {
+        let mut last = self.cons();
+        while let Some(tail) = unsafe { last.tail.as_ref() } {
+            last = tail;
+        }
+        unsafe { last.end.as_ref() }
+    }
 
-                if !new.tail.is_null() {
-                    let refs = new.refs;
-                    let mut tail = new.tail.read();
-                    let head = internal::alloc::value();
-                    if !tail.head.is_null() {
-                        head.write(tail.head.read());
-                    }
-                    new.head = head;
-                    self.refs = refs;
-                }
-            }
-        } else {
-            new.incr_ref();
-            self.incr_ref();
-            if self.tail.is_null() {
-                unsafe {
-                    let mut new_tail = std::ptr::from_mut::<Cell<'c>>(new);
-                    self.tail = new_tail;
+    /// `set_end` replaces the value that ends this list, see [`Cell::end`].
+    ///
+    /// As in Lisp, a [`Value::List`] end is appended to this list,
+    /// see [`Cell::add`], and a [`Value::Nil`] end makes it a proper
+    /// list, such that `(a . (b))` is `(a b)` and `(a . nil)` is `(a)`.
+    ///
+    /// Panics when this list has no values, since `(. b)` is no list.
+    pub fn set_end(&mut self, end: Value<'c>) {
+        assert!(self.iter().next().is_some(), "cannot set the end of an empty list");
+        let last = self.cons_mut().last_mut();
+        unsafe {
+            Cell::free_head(std::mem::replace(&mut last.end, internal::null::value()));
+        }
+        match end {
+            Value::Nil => {},
+            Value::List(mut list) => {
+                if !list.is_nil() {
+                    self.add(&mut list);
                 }
+            },
+            end => unsafe {
+                let ptr = internal::alloc::value();
+                ptr.write(end);
+                last.end = ptr;
+            },
+        }
+    }
+
+    /// `add` appends `new` to the end of this list sharing it, such
+    /// that `new` becomes a handle to the last cell of this list and
+    /// changes made through either one are seen by both.
+    ///
+    /// Appending to an improper list replaces its end, as `nconc`
+    /// does in Lisp.
+    pub fn add(&mut self, new: &mut Cell<'c>) {
+        let shared = new.share_mut();
+        unsafe {
+            (*shared).refs += 1;
+        }
+        let cell = self.cons_mut();
+        if cell.head.is_null() && cell.tail.is_null() {
+            if self.shared.is_null() {
+                self.shared = shared;
             } else {
                 unsafe {
-                    let mut tail = &mut *self.tail;
-                    tail.add(new);
+                    Cell::release(std::mem::replace(&mut self.shared, shared));
                 }
             }
+        } else {
+            let last = cell.last_mut();
+            unsafe {
+                Cell::free_head(std::mem::replace(&mut last.end, internal::null::value()));
+            }
+            last.tail = shared;
         }
     }
 
+    /// `share` returns a handle to the same cells as this list, see
+    /// [`Cell::add`]
+    pub fn share(&mut self) -> Cell<'c> {
+        let shared = self.share_mut();
+        unsafe { Cell::handle(shared) }
+    }
+
     pub fn pop(&mut self) -> bool {
-        if !self.tail.is_null() {
+        let cell = self.cons_mut();
+        if !cell.tail.is_null() {
             unsafe {
-                self.tail = internal::null::cell();
+                Cell::release(std::mem::replace(&mut cell.tail, internal::null::cell()));
             }
             true
-        } else if !self.head.is_null() {
-            self.head = internal::null::value();
+        } else if !cell.end.is_null() {
+            unsafe {
+                Cell::free_head(std::mem::replace(&mut cell.end, internal::null::value()));
+            }
+            true
+        } else if !cell.head.is_null() {
+            unsafe {
+                Cell::free_head(std::mem::replace(&mut cell.head, internal::null::value()));
+            }
             true
         } else {
             false
@@ -108,49 +177,220 @@ impl<'c> Cell<'c> {
     }
 
     pub fn len(&self) -> usize {
-        let mut len = 0;
-        if !self.head.is_null() {
-            len += 1
+        self.iter().count()
+    }
+
+    pub fn tail(&self) -> Option<&'c Cell<'c>> {
+        unsafe { self.cons().tail.as_ref() }
+    }
+
+    pub fn values(&self) -> Vec<Value<'c>> {
+        self.iter().cloned().collect()
+    }
+
+    /// `into_owned` copies this list into newly allocated cells whose
+    /// values borrow nothing, see [`Value::into_owned`].
+    ///
+    /// The copy shares no structure with this list or any other.
+    pub fn into_owned(self) -> Cell<'static> {
+        let mut cell: Cell<'static> = self.iter().map(|value| value.clone().into_owned()).collect();
+        if let Some(end) = self.end() {
+            cell.set_end(end.clone().into_owned());
         }
-        if let Some(tail) = self.tail() {
-            len += tail.len();
+        cell
+    }
+
+    /// `iter` iterates over references to the values of the list
+    pub fn iter(&self) -> Iter<'_, 'c> {
+        Iter {
+            cell: Some(self.cons()),
         }
-        len
     }
 
-    pub fn tail(&self) -> Option<&'c Cell<'c>> {
-        if self.tail.is_null() {
-            None
-        } else {
+    /// `map` returns a new list of the results of calling `f` on each
+    /// value of this list, see [`mapcar`](crate::mapcar)
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use ds::{list, Value};
+    ///
+    /// let cell = list!(1u8, 2u8, 3u8);
+    /// assert_eq!(cell.map(|value| (value * &Value::Byte(2)).unwrap()), list!(2u8, 4u8, 6u8));
+    /// ```
+    pub fn map<V: Into<Value<'c>>, F: FnMut(&Value<'c>) -> V>(&self, f: F) -> Cell<'c> {
+        self.iter().map(f).collect()
+    }
+
+    /// `filter` returns a new list of the values of this list for
+    /// which `predicate` returns `true`, see [`remove_if`](crate::remove_if)
+    pub fn filter<P: FnMut(&Value<'c>) -> bool>(&self, mut predicate: P) -> Cell<'c> {
+        self.iter().filter(|value| predicate(value)).cloned().collect()
+    }
+
+    /// `fold_left` combines the values of this list from the first to
+    /// the last, starting with `init`, as `f(f(f(init, a), b), c)`
+    pub fn fold_left<A, F: FnMut(A, &Value<'c>) -> A>(&self, init: A, f: F) -> A {
+        self.iter().fold(init, f)
+    }
+
+    /// `fold_right` combines the values of this list from the last to
+    /// the first, starting with `init`, as `f(a, f(b, f(c, init)))`
+    pub fn fold_right<A, F: FnMut(&Value<'c>, A) -> A>(&self, init: A, mut f: F) -> A {
+        let values = self.iter().collect::<Vec<_>>();
+        values.into_iter().rev().fold(init, |acc, value| f(value, acc))
+    }
+
+    /// `reduce` combines the values of this list from the first to
+    /// the last, starting with the first value, or returns `None`
+    /// when the list is empty, see [`reduce`](crate::reduce)
+    pub fn reduce<F: FnMut(Value<'c>, &Value<'c>) -> Value<'c>>(&self, f: F) -> Option<Value<'c>> {
+        let mut values = self.iter();
+        let first = values.next()?.clone();
+        Some(values.fold(first, f))
+    }
+
+    /// `any` tells whether `predicate` returns `true` for some value
+    /// of this list, which is `false` for `nil`
+    pub fn any<P: FnMut(&Value<'c>) -> bool>(&self, predicate: P) -> bool {
+        self.iter().any(predicate)
+    }
+
+    /// `all` tells whether `predicate` returns `true` for every value
+    /// of this list, which is `true` for `nil`
+    pub fn all<P: FnMut(&Value<'c>) -> bool>(&self, predicate: P) -> bool {
+        self.iter().all(predicate)
+    }
+
+    /// `find` returns the first value of this list for which
+    /// `predicate` returns `true`
+    pub fn find<P: FnMut(&Value<'c>) -> bool>(&self, mut predicate: P) -> Option<&Value<'c>> {
+        self.iter().find(|value| predicate(value))
+    }
+
+    /// `push_values` appends each value of `values` to the end of the
+    /// list in newly allocated cells, replacing the end of an
+    /// improper list, see [`Cell::add`]
+    fn push_values<I: Iterator<Item = Value<'c>>>(&mut self, values: I) {
+        let mut last = self.cons_mut().last_mut();
+        let mut values = values.peekable();
+        if values.peek().is_some() {
             unsafe {
-                if let Some(tail) = self.tail.as_ref() {
-                    Some(tail)
-                } else {
-                    None
+                Cell::free_head(std::mem::replace(&mut last.end, internal::null::value()));
+            }
+        }
+        for value in values {
+            if last.head.is_null() && last.tail.is_null() {
+                unsafe {
+                    let head = internal::alloc::value();
+                    head.write(value);
+                    last.head = head;
                 }
+            } else {
+                let tail = Cell::alloc(Cell::new(value));
+                last.tail = tail;
+                last = unsafe { &mut *tail };
             }
         }
     }
 
-    pub fn values(&self) -> Vec<Value<'c>> {
-        let mut values = Vec::<Value>::new();
-        if let Some(head) = self.head() {
-            values.push(head.clone());
+    /// `has_head` tells whether the first cell of this list holds a
+    /// value, which the empty cells left by [`Cell::add`] do not
+    pub(crate) fn has_head(&self) -> bool {
+        !self.cons().head.is_null()
+    }
+
+    /// `cdr` returns a handle to the tail of this list, see [`cdr`]
+    pub(crate) fn share_tail(&self) -> Cell<'c> {
+        let tail = self.cons().tail;
+        if tail.is_null() {
+            Cell::nil()
+        } else {
+            unsafe { Cell::handle(tail) }
+        }
+    }
+
+    /// `cons` returns the cell holding the head and tail of this
+    /// list, which is a cell on the heap when this [`Cell`] is a handle
+    fn cons(&self) -> &Cell<'c> {
+        if self.shared.is_null() {
+            self
+        } else {
+            unsafe { &*self.shared }
+        }
+    }
+
+    fn cons_mut(&mut self) -> &mut Cell<'c> {
+        if self.shared.is_null() {
+            self
+        } else {
+            unsafe { &mut *self.shared }
+        }
+    }
+
+    fn last_mut(&mut self) -> &mut Cell<'c> {
+        let mut last = self;
+        while !last.tail.is_null() {
+            last = unsafe { &mut *last.tail };
         }
-        if let Some(tail) = self.tail() {
-            values.extend(tail.values());
+        last
+    }
+
+    /// `share_mut` moves the head, tail and end of this cell to the
+    /// heap unless it already is a handle and returns the shared cell
+    fn share_mut(&mut self) -> *mut Cell<'c> {
+        if self.shared.is_null() {
+            let mut cell = Cell::nil();
+            cell.head = std::mem::replace(&mut self.head, internal::null::value());
+            cell.tail = std::mem::replace(&mut self.tail, internal::null::cell());
+            cell.end = std::mem::replace(&mut self.end, internal::null::value());
+            self.shared = Cell::alloc(cell);
+        }
+        self.shared
+    }
+
+    /// `alloc` moves `cell` to the heap with a single owner
+    fn alloc(mut cell: Cell<'c>) -> *mut Cell<'c> {
+        cell.refs = 1;
+        unsafe {
+            let ptr = internal::alloc::cell();
+            ptr.write(cell);
+            ptr
         }
-        values
     }
 
-    fn incr_ref(&mut self) {
-        self.refs += 1;
-        if !self.tail.is_null() {
+    /// `handle` creates a handle to the heap cell `shared` as one more owner
+    unsafe fn handle(shared: *mut Cell<'c>) -> Cell<'c> {
+        unsafe {
+            (*shared).refs += 1;
+        }
+        let mut cell = Cell::nil();
+        cell.shared = shared;
+        cell
+    }
+
+    /// `release` drops one owner of the heap cell `cell` and frees it,
+    /// along with every cell of its tail left without owners
+    unsafe fn release(mut cell: *mut Cell<'c>) {
+        while !cell.is_null() {
             unsafe {
-                let mut tail = self.tail as *mut Cell<'c>;
-                if let Some(mut tail) = tail.as_mut() {
-                    tail.refs += 1;
+                (*cell).refs = (*cell).refs.saturating_sub(1);
+                if (*cell).refs > 0 {
+                    return;
                 }
+                let tail = std::mem::replace(&mut (*cell).tail, internal::null::cell());
+                std::ptr::drop_in_place(cell);
+                internal::dealloc::cell(cell);
+                cell = tail;
+            }
+        }
+    }
+
+    unsafe fn free_head(head: *mut Value<'c>) {
+        if !head.is_null() {
+            unsafe {
+                std::ptr::drop_in_place(head);
+                internal::dealloc::value(head);
             }
         }
     }
@@ -195,20 +435,139 @@ impl<'c> From<i64> for Cell<'c> {
         Cell::new(Value::from(value))
     }
 }
+impl<'c> From<Symbol> for Cell<'c> {
+    fn from(value: Symbol) -> Cell<'c> {
+        Cell::new(Value::Symbol(value))
+    }
+}
+impl<'c> From<f64> for Cell<'c> {
+    fn from(value: f64) -> Cell<'c> {
+        Cell::new(Value::Float(value))
+    }
+}
+impl<'c> From<bool> for Cell<'c> {
+    fn from(value: bool) -> Cell<'c> {
+        Cell::new(Value::Bool(value))
+    }
+}
+impl<'c> From<char> for Cell<'c> {
+    fn from(value: char) -> Cell<'c> {
+        Cell::new(Value::Char(value))
+    }
+}
 
-impl<'c> PartialEq<Cell<'c>> for Cell<'c> {
-    fn eq(&self, other: &Cell<'c>) -> bool {
-        if self.head.is_null() == other.head.is_null() {
-            true
-        } else if let Some(head) = self.head() {
-            if let Some(value) = other.head() {
-                return head == value && (self.tail() == other.tail());
-            } else {
-                false
+/// `Iter` iterates over references to the values of a [`Cell`], see [`Cell::iter`]
+pub struct Iter<'a, 'c> {
+    cell: Option<&'a Cell<'c>>,
+}
+
+impl<'a, 'c> Iterator for Iter<'a, 'c> {
+    type Item = &'a Value<'c>;
+
+    fn next(&mut self) -> Option<&'a Value<'c>> {
+        while let Some(cell) = self.cell {
+            self.cell = unsafe { cell.tail.as_ref() };
+            if let Some(head) = unsafe { cell.head.as_ref() } {
+                return Some(head);
             }
-        } else {
-            false
         }
+        None
+    }
+}
+
+/// `IntoIter` iterates over the values of a [`Cell`] it owns, see
+/// [`Cell::into_iter`]
+pub struct IntoIter<'c> {
+    head: Option<Value<'c>>,
+    tail: *const Cell<'c>,
+    cell: Cell<'c>,
+}
+
+impl<'c> Iterator for IntoIter<'c> {
+    type Item = Value<'c>;
+
+    fn next(&mut self) -> Option<Value<'c>> {
+        if let Some(head) = self.head.take() {
+            return Some(head);
+        }
+        let mut iter = Iter {
+            cell: unsafe { self.tail.as_ref() },
+        };
+        let value = iter.next().cloned();
+        self.tail = iter.cell.map_or(std::ptr::null(), |cell| cell as *const Cell<'c>);
+        value
+    }
+}
+
+impl<'c> IntoIterator for Cell<'c> {
+    type Item = Value<'c>;
+    type IntoIter = IntoIter<'c>;
+
+    fn into_iter(self) -> IntoIter<'c> {
+        IntoIter {
+            head: self.head(),
+            tail: self.cons().tail,
+            cell: self,
+        }
+    }
+}
+
+impl<'a, 'c> IntoIterator for &'a Cell<'c> {
+    type Item = &'a Value<'c>;
+    type IntoIter = Iter<'a, 'c>;
+
+    fn into_iter(self) -> Iter<'a, 'c> {
+        self.iter()
+    }
+}
+
+impl<'c, T: Into<Value<'c>>> FromIterator<T> for Cell<'c> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Cell<'c> {
+        let mut cell = Cell::nil();
+        cell.extend(iter);
+        cell
+    }
+}
+
+impl<'c, T: Into<Value<'c>>> Extend<T> for Cell<'c> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.push_values(iter.into_iter().map(Into::into));
+    }
+}
+
+impl<'a, 'b> PartialEq<Cell<'b>> for Cell<'a> {
+    fn eq(&self, other: &Cell<'b>) -> bool {
+        self.iter().eq(other.iter()) && self.end().into_iter().eq(other.end())
+    }
+}
+
+impl<'c> Eq for Cell<'c> {}
+
+impl Hash for Cell<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let mut len = 0;
+        for value in self.iter() {
+            value.hash(state);
+            len += 1;
+        }
+        state.write_usize(len);
+        self.end().hash(state);
+    }
+}
+
+impl<'c> PartialOrd<Cell<'c>> for Cell<'c> {
+    fn partial_cmp(&self, other: &Cell<'c>) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Lists are ordered lexicographically by their values, such that a
+/// list sorts before any longer list it is a prefix of, then by their
+/// ends, such that a proper list sorts before an improper list of the
+/// same values.
+impl<'c> Ord for Cell<'c> {
+    fn cmp(&self, other: &Cell<'c>) -> std::cmp::Ordering {
+        self.iter().cmp(other.iter()).then_with(|| self.end().cmp(&other.end()))
     }
 }
 
@@ -219,19 +578,9 @@ impl<'c> Default for Cell<'c> {
 }
 impl<'c> Clone for Cell<'c> {
     fn clone(&self) -> Cell<'c> {
-        let mut cell = Cell::nil();
-        unsafe {
-            if !self.head.is_null() {
-                let head = internal::alloc::value();
-                head.write(self.head.read());
-                cell.head = head;
-            }
-            if !self.tail.is_null() {
-                let tail = internal::alloc::cell();
-                tail.write(self.tail.read());
-                cell.refs = self.refs;
-                cell.tail = tail;
-            }
+        let mut cell: Cell<'c> = self.iter().cloned().collect();
+        if let Some(end) = self.end() {
+            cell.set_end(end.clone());
         }
         cell
     }
@@ -241,21 +590,38 @@ impl<'c> Drop for Cell<'c> {
         #[rustfmt::skip]#[cfg(feature="debug")]
         eprintln!("{}",color::reset(color::bgfg(format!("{}{}{}{}:{}",crate::color::fg("dropping ",196),crate::color::fg("cell",49),color::bgfg(format!("@"),231,16),color::ptr_inv(self),color::fore(format!("{:#?}",self),201)),197,16)));
 
-        if self.refs > 0 {
-            #[rustfmt::skip]#[cfg(feature="debug")]
-            eprintln!("{}",color::reset(color::bgfg(format!("{}{}{}{}:{}",crate::color::fg("decrementing refs of ",220),crate::color::fg("cell",49),color::bgfg(format!("@"),231,16),color::ptr_inv(self),color::fore(format!("{:#?}",self),201)),197,16)));
-            self.refs -= 1;
+        unsafe {
+            Cell::release(std::mem::replace(&mut self.shared, internal::null::cell()));
+            Cell::free_head(std::mem::replace(&mut self.head, internal::null::value()));
+            Cell::free_head(std::mem::replace(&mut self.end, internal::null::value()));
+            Cell::release(std::mem::replace(&mut self.tail, internal::null::cell()));
+        }
+    }
+}
+
+/// Prints the [`Cell`] as an s-expression that [`sexp::read`](crate::sexp::read)
+/// reads back, with each element printed by [`Value`]'s `Debug`
+/// implementation: `(head "middle" 33u8 tail)`.
+///
+/// The alternate flag (i.e.: `{:#}`) breaks lists that do not fit in
+/// [`sexp::PRETTY_WIDTH`](crate::sexp::PRETTY_WIDTH) columns into one
+/// element per line, see [`sexp::pretty`](crate::sexp::pretty).
+///
+/// Improper lists print their end after a `.`, as in `(a b . c)`,
+/// see [`Cell::end`].
+impl std::fmt::Display for Cell<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if f.alternate() {
+            crate::sexp::write_pretty(f, self, 0, crate::sexp::PRETTY_WIDTH)
         } else {
-            unsafe {
-                internal::dealloc::value(self.head);
-                internal::dealloc::cell(self.tail);
-            }
+            crate::sexp::write(f, self)
         }
     }
 }
 
 impl std::fmt::Debug for Cell<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let cell = self.cons();
         write!(
             f,
             "{}{}{}{}[head:{} | tail:{}]",
@@ -263,15 +629,15 @@ impl std::fmt::Debug for Cell<'_> {
             crate::color::fg("Cell", 87),
             crate::color::fg("@", 231),
             crate::color::ptr_inv(self),
-            if self.head.is_null() {
+            if cell.head.is_null() {
                 color::fore("null", 196)
             } else {
-                color::ptr(self.head)
+                color::ptr(cell.head)
             },
-            if self.tail.is_null() {
+            if cell.tail.is_null() {
                 color::fore("null", 196)
             } else {
-                color::ptr(self.tail)
+                color::ptr(cell.tail)
             },
         )
     }
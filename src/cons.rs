@@ -2,12 +2,35 @@ use std::rc::Rc;
 
 use crate::{step, Cell, Value};
 
+/// `list!` builds a [`Cell`] from literals and identifiers, where an
+/// identifier becomes a [`Value::Symbol`] and a bracketed group
+/// becomes a nested [`Value::List`]:
+///
+/// ```
+/// use ds::{list, Cell, Value};
+///
+/// let cell = list!(head, ["b", "c"], 33u8);
+/// assert_eq!(cell.to_string(), r#"(head ("b" "c") 33u8)"#);
+/// ```
 #[macro_export]
 macro_rules! list {
-    ($( $item:literal ),* ) => {{
-        let mut cell = Cell::nil();
-        $(cell.add(&mut Cell::from($item));
-        )*
+    (@push $cell:ident;) => {};
+    (@push $cell:ident; [ $( $nested:tt )* ] $(, $( $rest:tt )* )?) => {
+        $cell.add(&mut $crate::Cell::from($crate::Value::List($crate::list!($( $nested )*))));
+        $crate::list!(@push $cell; $($( $rest )*)?);
+    };
+    (@push $cell:ident; $item:literal $(, $( $rest:tt )* )?) => {
+        $cell.add(&mut $crate::Cell::from($item));
+        $crate::list!(@push $cell; $($( $rest )*)?);
+    };
+    (@push $cell:ident; $item:ident $(, $( $rest:tt )* )?) => {
+        $cell.add(&mut $crate::Cell::from($crate::symbol(stringify!($item))));
+        $crate::list!(@push $cell; $($( $rest )*)?);
+    };
+    ($( $items:tt )*) => {{
+        #[allow(unused_mut)]
+        let mut cell = $crate::Cell::nil();
+        $crate::list!(@push cell; $( $items )*);
         cell
     }};
 }
@@ -18,11 +41,7 @@ pub fn cons<'c, H: Into<Value<'c>>>(head: H, tail: &mut Cell<'c>) -> Cell<'c> {
     head
 }
 pub fn cdr<'c>(cell: &Cell<'c>) -> Cell<'c> {
-    if let Some(tail) = cell.tail() {
-        tail.clone()
-    } else {
-        Cell::nil()
-    }
+    cell.share_tail()
 }
 pub fn car<'c>(cell: &Cell<'c>) -> Value<'c> {
     if let Some(head) = cell.head() {
@@ -31,3 +50,143 @@ pub fn car<'c>(cell: &Cell<'c>) -> Value<'c> {
         Value::nil()
     }
 }
+
+/// `mapcar` returns a new list of the results of calling `f` on each
+/// element of `cell`, see [`Cell::map`]
+pub fn mapcar<'c, V: Into<Value<'c>>, F: FnMut(&Value<'c>) -> V>(
+    f: F,
+    cell: &Cell<'c>,
+) -> Cell<'c> {
+    cell.map(f)
+}
+
+/// `remove_if` returns a new list of the elements of `cell` for
+/// which `predicate` returns `false`, see [`Cell::filter`]
+pub fn remove_if<'c, P: FnMut(&Value<'c>) -> bool>(mut predicate: P, cell: &Cell<'c>) -> Cell<'c> {
+    cell.filter(|value| !predicate(value))
+}
+
+/// `reduce` combines the elements of `cell` with `f` from the first
+/// to the last, returning [`Value::Nil`] when `cell` is `nil`, see
+/// [`Cell::reduce`]
+///
+/// Example
+///
+/// ```
+/// use ds::{list, reduce, Value};
+///
+/// let sum = reduce(|a, b| (&a + b).unwrap(), &list!(1u8, 2u8, 3u8));
+/// assert_eq!(sum, Value::Byte(6));
+/// ```
+pub fn reduce<'c, F: FnMut(Value<'c>, &Value<'c>) -> Value<'c>>(
+    f: F,
+    cell: &Cell<'c>,
+) -> Value<'c> {
+    cell.reduce(f).unwrap_or_default()
+}
+
+/// `length` returns the number of elements of `cell`, which is `0`
+/// for `nil`
+pub fn length(cell: &Cell<'_>) -> usize {
+    cell.len()
+}
+
+/// `nth` returns the element of `cell` at index `n`, or
+/// [`Value::Nil`] when `cell` has `n` elements or fewer, as
+/// [`car`] of [`nthcdr`] does
+pub fn nth<'c>(n: usize, cell: &Cell<'c>) -> Value<'c> {
+    cell.iter().nth(n).cloned().unwrap_or_default()
+}
+
+/// `nthcdr` returns the tail of `cell` left after calling [`cdr`]
+/// `n` times, which is `nil` when `cell` has `n` elements or fewer.
+///
+/// Like [`Cell::iter`], `nthcdr` steps over the empty cells left by
+/// adding `nil` to a list, see [`Cell::add`], such that the result
+/// starts at the element [`nth`] returns.
+///
+/// The result shares structure with `cell`, such that `nthcdr(0, cell)`
+/// is a handle to `cell` itself, see [`Cell::share`].
+pub fn nthcdr<'c>(n: usize, cell: &mut Cell<'c>) -> Cell<'c> {
+    if n == 0 {
+        return cell.share();
+    }
+    let mut rest = skip_empty(cell.share());
+    for _ in 0..n {
+        if rest.is_nil() {
+            break;
+        }
+        rest = skip_empty(cdr(&rest));
+    }
+    rest
+}
+
+/// `skip_empty` returns the tail of `cell` that starts at its first
+/// cell holding an element
+fn skip_empty(mut cell: Cell<'_>) -> Cell<'_> {
+    while !cell.has_head() && !cell.is_nil() {
+        cell = cdr(&cell);
+    }
+    cell
+}
+
+/// `last` returns the last cons of `cell`, that is, the one-element
+/// tail holding its last element, or `nil` when `cell` is `nil`.
+///
+/// The result shares structure with `cell`.
+pub fn last<'c>(cell: &mut Cell<'c>) -> Cell<'c> {
+    match length(cell) {
+        0 => Cell::nil(),
+        len => nthcdr(len - 1, cell),
+    }
+}
+
+/// `butlast` returns a copy of `cell` without its last element,
+/// which is `nil` when `cell` has one element or none
+pub fn butlast<'c>(cell: &Cell<'c>) -> Cell<'c> {
+    let len = length(cell);
+    cell.iter().take(len.saturating_sub(1)).cloned().collect()
+}
+
+/// `member` returns the tail of `cell` starting at the first element
+/// equal to `item`, or `nil` when no element is.
+///
+/// The result shares structure with `cell`, such that changes made
+/// through it are seen by `cell`.
+pub fn member<'c>(item: &Value<'_>, cell: &mut Cell<'c>) -> Cell<'c> {
+    match cell.iter().position(|value| value == item) {
+        Some(index) => nthcdr(index, cell),
+        None => Cell::nil(),
+    }
+}
+
+/// `append` returns a list of the elements of `list` followed by
+/// those of `tail`.
+///
+/// As in Lisp, the cells of `list` are copied while `tail` is shared
+/// by the result rather than copied, see [`Cell::add`]. Appending to
+/// `nil` returns a handle to `tail`.
+pub fn append<'c>(list: &Cell<'c>, tail: &mut Cell<'c>) -> Cell<'c> {
+    if list.is_nil() {
+        return tail.share();
+    }
+    let mut cell = list.iter().cloned().collect::<Cell>();
+    if !tail.is_nil() {
+        cell.add(tail);
+    }
+    cell
+}
+
+/// `reverse` returns a new list with the elements of `cell` in
+/// reverse order, consing each element onto the ones before it
+pub fn reverse<'c>(cell: &Cell<'c>) -> Cell<'c> {
+    let mut reversed = Cell::nil();
+    for value in cell.iter() {
+        reversed = if reversed.is_nil() {
+            Cell::new(value.clone())
+        } else {
+            cons(value.clone(), &mut reversed)
+        };
+    }
+    reversed
+}
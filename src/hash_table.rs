@@ -0,0 +1,385 @@
+use std::hash::{BuildHasher, RandomState};
+use std::iter::{Extend, FromIterator, IntoIterator};
+
+use crate::{internal, Value};
+
+/// `MIN_CAPACITY` is the number of slots allocated by the first insertion
+const MIN_CAPACITY: usize = 8;
+
+/// `HashTable` maps [`Value`] keys to [`Value`]s with open
+/// addressing and linear probing in a single array of slots, which
+/// doubles in size before the slots in use, including those left by
+/// removed entries, exceed [`HashTable::MAX_LOAD_FACTOR`].
+///
+/// Keys are hashed with [`Value`]'s [`Hash`](std::hash::Hash)
+/// implementation and compared with its [`PartialEq`], such that
+/// `Value::Byte(1)` and `Value::UInt(1)` are different keys.
+///
+/// Example
+///
+/// ```
+/// use ds::{HashTable, Value};
+///
+/// let mut table = HashTable::new();
+/// table.insert("one", 1u8);
+/// table.insert(2u8, "two");
+/// assert_eq!(table.get(&Value::from("one")), Some(&Value::Byte(1)));
+/// assert_eq!(table.remove(&Value::Byte(2)), Some(Value::from("two")));
+/// assert_eq!(table.len(), 1);
+/// ```
+pub struct HashTable<'c> {
+    slots: *mut Slot<'c>,
+    capacity: usize,
+    len: usize,
+    tombstones: usize,
+    hasher: RandomState,
+}
+
+/// `Slot` is an entry of the array of a [`HashTable`], where
+/// `Deleted` marks a removed entry so that probing continues past it
+pub(crate) enum Slot<'c> {
+    Empty,
+    Deleted,
+    Occupied {
+        hash: u64,
+        key: Value<'c>,
+        value: Value<'c>,
+    },
+}
+
+/// `HashTableStats` describes the occupancy of a [`HashTable`], see
+/// [`HashTable::stats`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HashTableStats {
+    /// number of entries
+    pub len: usize,
+    /// number of slots
+    pub capacity: usize,
+    /// number of slots left by removed entries
+    pub tombstones: usize,
+    /// `len / capacity`
+    pub load_factor: f64,
+    /// longest distance between an entry and the slot its hash points to
+    pub max_probe: usize,
+    /// average distance between an entry and the slot its hash points to
+    pub average_probe: f64,
+}
+
+impl<'c> HashTable<'c> {
+    /// `MAX_LOAD_FACTOR` is the ratio of slots in use, including
+    /// those left by removed entries, above which the table resizes
+    pub const MAX_LOAD_FACTOR: f64 = 0.75;
+
+    pub fn new() -> HashTable<'c> {
+        HashTable {
+            slots: internal::null::ptr(),
+            capacity: 0,
+            len: 0,
+            tombstones: 0,
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// `with_capacity` allocates enough slots for `len` entries
+    pub fn with_capacity(len: usize) -> HashTable<'c> {
+        let mut table = HashTable::new();
+        if len > 0 {
+            table.resize(HashTable::capacity_for(len));
+        }
+        table
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// `capacity` is the number of slots allocated
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// `load_factor` is the ratio of entries to slots
+    pub fn load_factor(&self) -> f64 {
+        if self.capacity == 0 {
+            0.0
+        } else {
+            self.len as f64 / self.capacity as f64
+        }
+    }
+
+    /// `stats` measures the occupancy of the table and the length of
+    /// the probes needed to find its entries
+    pub fn stats(&self) -> HashTableStats {
+        let mut max_probe = 0;
+        let mut total_probe = 0;
+        for (index, slot) in self.slots().iter().enumerate() {
+            if let Slot::Occupied { hash, .. } = slot {
+                let probe = index.wrapping_sub(self.home(*hash)) & (self.capacity - 1);
+                max_probe = max_probe.max(probe);
+                total_probe += probe;
+            }
+        }
+        HashTableStats {
+            len: self.len,
+            capacity: self.capacity,
+            tombstones: self.tombstones,
+            load_factor: self.load_factor(),
+            max_probe,
+            average_probe: if self.len == 0 { 0.0 } else { total_probe as f64 / self.len as f64 },
+        }
+    }
+
+    /// `insert` maps `key` to `value`, returning the value previously
+    /// mapped to `key`, if any
+    pub fn insert<K: Into<Value<'c>>, V: Into<Value<'c>>>(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Option<Value<'c>> {
+        let key = key.into();
+        let value = value.into();
+        let hash = self.hash(&key);
+        if let Some(index) = self.find(&key, hash)
+            && let Slot::Occupied { value: old, .. } = &mut self.slots_mut()[index]
+        {
+            return Some(std::mem::replace(old, value));
+        }
+        if (self.len + self.tombstones + 1) as f64 > self.capacity as f64 * Self::MAX_LOAD_FACTOR {
+            self.resize(HashTable::capacity_for(self.len + 1));
+        }
+        let index = self.vacant(hash);
+        let slot =
+            std::mem::replace(&mut self.slots_mut()[index], Slot::Occupied { hash, key, value });
+        if let Slot::Deleted = slot {
+            self.tombstones -= 1;
+        }
+        self.len += 1;
+        None
+    }
+
+    pub fn get(&self, key: &Value<'_>) -> Option<&Value<'c>> {
+        let index = self.find(key, self.hash(key))?;
+        match &self.slots()[index] {
+            Slot::Occupied { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, key: &Value<'_>) -> Option<&mut Value<'c>> {
+        let index = self.find(key, self.hash(key))?;
+        match &mut self.slots_mut()[index] {
+            Slot::Occupied { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn contains_key(&self, key: &Value<'_>) -> bool {
+        self.find(key, self.hash(key)).is_some()
+    }
+
+    /// `remove` removes `key` from the table and returns the value
+    /// mapped to it, if any
+    pub fn remove(&mut self, key: &Value<'_>) -> Option<Value<'c>> {
+        let index = self.find(key, self.hash(key))?;
+        self.len -= 1;
+        self.tombstones += 1;
+        match std::mem::replace(&mut self.slots_mut()[index], Slot::Deleted) {
+            Slot::Occupied { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// `clear` removes every entry keeping the slots allocated
+    pub fn clear(&mut self) {
+        for slot in self.slots_mut() {
+            *slot = Slot::Empty;
+        }
+        self.len = 0;
+        self.tombstones = 0;
+    }
+
+    /// `iter` iterates over the entries of the table in no particular order
+    pub fn iter(&self) -> Iter<'_, 'c> {
+        Iter {
+            slots: self.slots().iter(),
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &Value<'c>> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &Value<'c>> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    fn hash(&self, key: &Value<'_>) -> u64 {
+        self.hasher.hash_one(key)
+    }
+
+    /// `home` is the slot in which probing for `hash` starts
+    fn home(&self, hash: u64) -> usize {
+        hash as usize & (self.capacity - 1)
+    }
+
+    /// `find` returns the index of the slot holding `key`
+    fn find(&self, key: &Value<'_>, hash: u64) -> Option<usize> {
+        if self.len == 0 {
+            return None;
+        }
+        let slots = self.slots();
+        let mut index = self.home(hash);
+        for _ in 0..self.capacity {
+            match &slots[index] {
+                Slot::Empty => return None,
+                Slot::Occupied {
+                    hash: other,
+                    key: other_key,
+                    ..
+                } if *other == hash && other_key == key => return Some(index),
+                _ => {},
+            }
+            index = (index + 1) & (self.capacity - 1);
+        }
+        None
+    }
+
+    /// `vacant` returns the index of the first slot not in use along
+    /// the probe sequence of `hash`
+    fn vacant(&self, hash: u64) -> usize {
+        let slots = self.slots();
+        let mut index = self.home(hash);
+        while let Slot::Occupied { .. } = slots[index] {
+            index = (index + 1) & (self.capacity - 1);
+        }
+        index
+    }
+
+    /// `capacity_for` is the smallest power of two number of slots
+    /// that holds `len` entries within [`HashTable::MAX_LOAD_FACTOR`]
+    fn capacity_for(len: usize) -> usize {
+        let mut capacity = MIN_CAPACITY;
+        while len as f64 > capacity as f64 * Self::MAX_LOAD_FACTOR {
+            capacity *= 2;
+        }
+        capacity
+    }
+
+    /// `resize` moves the entries into `capacity` new slots,
+    /// discarding the slots left by removed entries
+    fn resize(&mut self, capacity: usize) {
+        let slots = std::mem::replace(&mut self.slots, unsafe { internal::alloc::slots(capacity) });
+        let old_capacity = std::mem::replace(&mut self.capacity, capacity);
+        for index in 0..capacity {
+            unsafe {
+                self.slots.add(index).write(Slot::Empty);
+            }
+        }
+        self.tombstones = 0;
+        if slots.is_null() {
+            return;
+        }
+        for index in 0..old_capacity {
+            if let Slot::Occupied { hash, key, value } = unsafe { slots.add(index).read() } {
+                let index = self.vacant(hash);
+                self.slots_mut()[index] = Slot::Occupied { hash, key, value };
+            }
+        }
+        unsafe {
+            internal::dealloc::slots(slots, old_capacity);
+        }
+    }
+
+    fn slots(&self) -> &[Slot<'c>] {
+        if self.slots.is_null() {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.slots, self.capacity) }
+        }
+    }
+
+    fn slots_mut(&mut self) -> &mut [Slot<'c>] {
+        if self.slots.is_null() {
+            &mut []
+        } else {
+            unsafe { std::slice::from_raw_parts_mut(self.slots, self.capacity) }
+        }
+    }
+}
+
+/// `Iter` iterates over the entries of a [`HashTable`], see [`HashTable::iter`]
+pub struct Iter<'a, 'c> {
+    slots: std::slice::Iter<'a, Slot<'c>>,
+}
+
+impl<'a, 'c> Iterator for Iter<'a, 'c> {
+    type Item = (&'a Value<'c>, &'a Value<'c>);
+
+    fn next(&mut self) -> Option<(&'a Value<'c>, &'a Value<'c>)> {
+        self.slots.find_map(|slot| match slot {
+            Slot::Occupied { key, value, .. } => Some((key, value)),
+            _ => None,
+        })
+    }
+}
+
+impl<'a, 'c> IntoIterator for &'a HashTable<'c> {
+    type Item = (&'a Value<'c>, &'a Value<'c>);
+    type IntoIter = Iter<'a, 'c>;
+
+    fn into_iter(self) -> Iter<'a, 'c> {
+        self.iter()
+    }
+}
+
+impl<'c, K: Into<Value<'c>>, V: Into<Value<'c>>> FromIterator<(K, V)> for HashTable<'c> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> HashTable<'c> {
+        let mut table = HashTable::new();
+        table.extend(iter);
+        table
+    }
+}
+
+impl<'c, K: Into<Value<'c>>, V: Into<Value<'c>>> Extend<(K, V)> for HashTable<'c> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'c> Default for HashTable<'c> {
+    fn default() -> HashTable<'c> {
+        HashTable::new()
+    }
+}
+
+impl<'c> Clone for HashTable<'c> {
+    fn clone(&self) -> HashTable<'c> {
+        let mut table = HashTable::with_capacity(self.len);
+        table.extend(self.iter().map(|(key, value)| (key.clone(), value.clone())));
+        table
+    }
+}
+
+impl<'c> Drop for HashTable<'c> {
+    fn drop(&mut self) {
+        if self.slots.is_null() {
+            return;
+        }
+        unsafe {
+            std::ptr::drop_in_place(self.slots_mut());
+            internal::dealloc::slots(self.slots, self.capacity);
+        }
+    }
+}
+
+impl std::fmt::Debug for HashTable<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -8,6 +8,7 @@ use std::mem::{ManuallyDrop, MaybeUninit};
 use std::ops::{Deref, DerefMut, Index, IndexMut};
 use std::ptr::NonNull;
 
+use crate::hash_table::Slot;
 use crate::{Cell, Node, Value};
 
 pub(super) mod null {
@@ -21,14 +22,14 @@ pub(super) mod null {
     pub(crate) fn cell<'c>() -> *mut Cell<'c> {
         self::ptr::<Cell<'c>>()
     }
-    pub(crate) fn node<'c>() -> *mut Node<'c> {
-        self::ptr::<Node<'c>>()
+    pub(crate) fn node<'c, T>() -> *mut Node<'c, T> {
+        self::ptr::<Node<'c, T>>()
     }
 }
 pub(super) mod alloc {
     use std::alloc::Layout;
 
-    use super::{Cell, Node, Value};
+    use super::{Cell, Node, Slot, Value};
     unsafe fn new<T>() -> *mut T {
         let layout = Layout::new::<T>();
         let ptr = unsafe {
@@ -46,14 +47,24 @@ pub(super) mod alloc {
     pub(crate) unsafe fn cell<'c>() -> *mut Cell<'c> {
         unsafe { self::new::<Cell<'c>>() }
     }
-    pub(crate) unsafe fn node<'c>() -> *mut Node<'c> {
-        unsafe { self::new::<Node<'c>>() }
+    pub(crate) unsafe fn node<'c, T>() -> *mut Node<'c, T> {
+        unsafe { self::new::<Node<'c, T>>() }
+    }
+    /// `slots` allocates `capacity` uninitialized slots of a
+    /// [`HashTable`](crate::HashTable)
+    pub(crate) unsafe fn slots<'c>(capacity: usize) -> *mut Slot<'c> {
+        let layout = Layout::array::<Slot<'c>>(capacity).expect("capacity overflow");
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        if ptr.is_null() {
+            std::alloc::handle_alloc_error(layout);
+        }
+        ptr as *mut Slot<'c>
     }
 }
 pub(super) mod dealloc {
     use std::alloc::Layout;
 
-    use super::{Cell, Node, Value};
+    use super::{Cell, Node, Slot, Value};
     unsafe fn free<T>(mut ptr: *mut T) {
         let layout = Layout::new::<T>();
         unsafe {
@@ -79,9 +90,17 @@ pub(super) mod dealloc {
         eprintln!("{} {} {}", crate::color::fg("freeing", 9), crate::color::fg("cell", 137), crate::color::ptr_inv(cell));
         unsafe { self::free::<Cell<'c>>(cell) }
     }
-    pub(crate) unsafe fn node<'c>(mut node: *mut Node<'c>) {
+    pub(crate) unsafe fn node<'c, T>(mut node: *mut Node<'c, T>) {
         #[rustfmt::skip]#[cfg(feature="debug")]
         eprintln!("{} {} {}", crate::color::fg("freeing", 9), crate::color::fg("node", 28), crate::color::ptr_inv(node));
-        unsafe { self::free::<Node<'c>>(node) }
+        unsafe { self::free::<Node<'c, T>>(node) }
+    }
+    /// `slots` frees the memory of `capacity` slots allocated by
+    /// [`super::alloc::slots`] without dropping them
+    pub(crate) unsafe fn slots<'c>(slots: *mut Slot<'c>, capacity: usize) {
+        #[rustfmt::skip]#[cfg(feature="debug")]
+        eprintln!("{} {} {}", crate::color::fg("freeing", 9), crate::color::fg("slots", 138), crate::color::ptr_inv(slots));
+        let layout = Layout::array::<Slot<'c>>(capacity).expect("capacity overflow");
+        unsafe { std::alloc::dealloc(slots as *mut u8, layout) }
     }
 }
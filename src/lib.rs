@@ -1,20 +1,38 @@
 #![allow(unused)]
 #![feature(intra_doc_pointers)]
+#![feature(layout_for_ptr)]
 pub mod traits;
 pub use traits::ListValue;
 pub mod cons;
-pub use cons::{car, cdr, cons};
+pub use cons::{car, cdr, cons, mapcar, reduce, remove_if};
 pub mod cell;
-pub use cell::Cell;
+pub use cell::{Cell, OwnedCell};
 pub mod value;
-pub use value::Value;
+pub use value::{OwnedValue, Value, ValueError, ValueKind};
+mod numeric;
+pub mod symbol;
+pub use symbol::{symbol, Symbol};
+pub mod sexp;
 pub mod node;
 pub use node::{subtree_delete, Node};
+pub mod binary_tree;
+pub use binary_tree::{BinaryTree, OwnedBinaryTree};
+pub mod hash_table;
+pub use hash_table::HashTable;
+pub mod traversal;
+#[cfg(feature = "serde")]
+pub mod serialization;
 pub mod color;
 pub mod macros;
 pub mod unique_pointer;
-pub use unique_pointer::UniquePointer;
+pub use unique_pointer::{PointerError, UniquePointer};
+pub mod weak_pointer;
+pub use weak_pointer::WeakPointer;
 pub mod refcounter;
 pub use refcounter::RefCounter;
+pub mod atomic_unique_pointer;
+pub use atomic_unique_pointer::AtomicUniquePointer;
+pub mod atomic_refcounter;
+pub use atomic_refcounter::AtomicRefCounter;
 pub(crate) mod internal;
 pub mod test;
@@ -7,19 +7,19 @@ macro_rules! cast_node_mut {
         $crate::cast_node_mut!($ptr, 'c, incr)
     }};
     ($ptr:expr, $lt:lifetime, incr ) => {{
-        let node = unsafe {
+        let node: &mut Node<$lt, _> = unsafe {
             let mut node = &mut *$ptr;
             node.incr_ref();
             node
         };
-        unsafe { std::mem::transmute::<&mut Node<$lt>, &$lt mut Node<$lt>>(node) }
+        unsafe { $crate::macros::node_mut(node) }
     }};
     ($ptr:expr, $lt:lifetime, noincr ) => {{
-        let node = unsafe {
+        let node: &mut Node<$lt, _> = unsafe {
             let mut node = &mut *$ptr;
             node
         };
-        unsafe { std::mem::transmute::<&mut Node<$lt>, &$lt mut Node<$lt>>(node) }
+        unsafe { $crate::macros::node_mut(node) }
     }};
 }
 
@@ -29,14 +29,30 @@ macro_rules! cast_node_ref {
         $crate::cast_node_ref!($ptr, 'c)
     }};
     ($ptr:expr, $lt:lifetime ) => {{
-        let node = unsafe {
+        let node: &Node<$lt, _> = unsafe {
             let mut node = & *$ptr;
             node
         };
-        unsafe { std::mem::transmute::<& Node<$lt>, &$lt Node<$lt>>(node) }
+        unsafe { $crate::macros::node_ref(node) }
     }};
 }
 
+/// `node_mut` extends the lifetime of a mutable reference to a
+/// [`Node`](crate::Node) to the lifetime of the node itself, see
+/// [`cast_node_mut`]
+#[doc(hidden)]
+pub unsafe fn node_mut<'c, T>(node: &mut crate::Node<'c, T>) -> &'c mut crate::Node<'c, T> {
+    unsafe { std::mem::transmute::<&mut crate::Node<'c, T>, &'c mut crate::Node<'c, T>>(node) }
+}
+
+/// `node_ref` extends the lifetime of a reference to a
+/// [`Node`](crate::Node) to the lifetime of the node itself, see
+/// [`cast_node_ref`]
+#[doc(hidden)]
+pub unsafe fn node_ref<'c, T>(node: &crate::Node<'c, T>) -> &'c crate::Node<'c, T> {
+    unsafe { std::mem::transmute::<&crate::Node<'c, T>, &'c crate::Node<'c, T>>(node) }
+}
+
 #[macro_export]
 macro_rules! decr_ref_nonzero {
     ($data_structure:expr) => {
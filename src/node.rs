@@ -9,27 +9,78 @@ use std::ops::{Deref, DerefMut, Index, IndexMut};
 use std::pin::Pin;
 use std::ptr::NonNull;
 
+use crate::traversal::{
+    InOrder, InOrderMut, LevelOrder, LevelOrderMut, PostOrder, PostOrderMut, PreOrder, PreOrderMut,
+};
 use crate::{
     cast_node_mut, cast_node_ref, color, decr_ref_nonzero, internal, step, step_test, warn,
-    warn_inv, RefCounter, UniquePointer, Value,
+    warn_inv, BinaryTree, ListValue, RefCounter, UniquePointer, Value, WeakPointer,
 };
 
-pub struct Node<'c> {
-    pub parent: UniquePointer<Node<'c>>,
-    pub left: UniquePointer<Node<'c>>,
-    pub right: UniquePointer<Node<'c>>,
-    pub item: UniquePointer<Value<'c>>,
+pub struct Node<'c, T = Value<'c>> {
+    pub parent: WeakPointer<Node<'c, T>>,
+    pub left: UniquePointer<Node<'c, T>>,
+    pub right: UniquePointer<Node<'c, T>>,
+    pub item: UniquePointer<T>,
     refs: RefCounter,
+    height: usize,
+    size: usize,
+    _marker: PhantomData<&'c T>,
 }
 
 impl<'c> Node<'c> {
+    /// `nil` creates an empty [`Node`] of [`Value`], see [`Node::empty`]
+    /// for other payloads
     pub fn nil() -> Node<'c> {
+        Node::empty()
+    }
+
+    /// `owned_subtree` copies the subtree rooted at this node, in the
+    /// same shape, into a new [`BinaryTree`] whose values borrow
+    /// nothing, see [`Value::into_owned`]
+    pub fn owned_subtree(&self) -> BinaryTree<'static> {
+        let mut tree = BinaryTree::new(self.item().into_owned());
+        let root = tree.root_mut().expect("tree created with a root");
+        link_owned(&tree, root, self);
+        tree
+    }
+}
+
+/// `link_owned` links copies of the children of `source` under
+/// `node`, from the top down, see [`Node::owned_subtree`]
+fn link_owned(tree: &BinaryTree<'static>, node: &mut Node<'static>, source: &Node<'_>) {
+    if let Some(left) = source.left() {
+        let child = tree.node(left.item().into_owned());
+        node.set_left(child);
+        link_owned(tree, child, left);
+    }
+    if let Some(right) = source.right() {
+        let child = tree.node(right.item().into_owned());
+        node.set_right(child);
+        link_owned(tree, child, right);
+    }
+}
+
+impl<'c, T: ListValue + Default> Node<'c, T> {
+    /// `item` returns a clone of the item of this node or the default
+    /// value of `T` when the node has no item
+    pub fn item(&self) -> T {
+        self.value().unwrap_or_default()
+    }
+}
+
+impl<'c, T: ListValue> Node<'c, T> {
+    /// `empty` creates a [`Node`] without an item
+    pub fn empty() -> Node<'c, T> {
         Node {
-            parent: UniquePointer::<Node<'c>>::null(),
-            left: UniquePointer::<Node<'c>>::null(),
-            right: UniquePointer::<Node<'c>>::null(),
-            item: UniquePointer::<Value<'c>>::null(),
+            parent: WeakPointer::<Node<'c, T>>::null(),
+            left: UniquePointer::<Node<'c, T>>::null(),
+            right: UniquePointer::<Node<'c, T>>::null(),
+            item: UniquePointer::<T>::null(),
             refs: RefCounter::new(),
+            height: 0,
+            size: 0,
+            _marker: PhantomData,
         }
     }
 
@@ -41,39 +92,36 @@ impl<'c> Node<'c> {
             && self.refs <= 1
     }
 
-    pub fn new(value: Value<'c>) -> Node<'c> {
-        let mut node = Node::nil();
+    pub fn new(value: T) -> Node<'c, T> {
+        let mut node = Node::<'c, T>::empty();
         unsafe {
             node.item.write(value);
         }
+        node.size = 1;
         node
     }
 
-    pub fn parent(&self) -> Option<&'c Node<'c>> {
+    pub fn parent(&self) -> Option<&'c Node<'c, T>> {
         self.parent.as_ref()
     }
 
-    pub fn parent_mut(&mut self) -> Option<&'c mut Node<'c>> {
+    pub fn parent_mut(&mut self) -> Option<&'c mut Node<'c, T>> {
         self.parent.as_mut()
     }
 
-    pub fn item(&self) -> Value<'c> {
-        self.value().unwrap_or_default()
-    }
-
     pub fn id(&self) -> String {
         format!(
             "{}{}",
             if self.item.is_null() {
                 format!("Null Node {:p}", self)
             } else {
-                format!("Node {}", self.item())
+                format!("Node {}", self.item.inner_ref())
             },
             format!(" ({} referefences)", self.refs)
         )
     }
 
-    pub fn value(&self) -> Option<Value<'c>> {
+    pub fn value(&self) -> Option<T> {
         if self.item.is_null() {
             None
         } else {
@@ -87,7 +135,7 @@ impl<'c> Node<'c> {
         }
     }
 
-    pub fn parent_value(&self) -> Option<Value<'c>> {
+    pub fn parent_value(&self) -> Option<T> {
         if let Some(parent) = self.parent() {
             parent.value()
         } else {
@@ -95,18 +143,26 @@ impl<'c> Node<'c> {
         }
     }
 
-    pub fn set_left(&mut self, left: &mut Node<'c>) {
-        self.incr_ref();
-        left.parent = self.ptr();
+    /// `set_left` links `left` as the left child of this node,
+    /// releasing the link to the previous left child, if any
+    pub fn set_left(&mut self, left: &mut Node<'c, T>) {
+        self.take_left();
+        left.parent.dealloc();
+        left.parent = self.weak_ptr();
         self.left = left.ptr();
         left.incr_ref();
+        self.update_ancestors();
     }
 
-    pub fn set_right(&mut self, right: &mut Node<'c>) {
-        self.incr_ref();
-        right.parent = self.ptr();
+    /// `set_right` links `right` as the right child of this node,
+    /// releasing the link to the previous right child, if any
+    pub fn set_right(&mut self, right: &mut Node<'c, T>) {
+        self.take_right();
+        right.parent.dealloc();
+        right.parent = self.weak_ptr();
         self.right = right.ptr();
         right.incr_ref();
+        self.update_ancestors();
     }
 
     pub fn delete_left(&mut self) {
@@ -117,18 +173,19 @@ impl<'c> Node<'c> {
         left.decr_ref();
         self.left.dealloc(true);
         self.left = UniquePointer::null();
+        self.update_ancestors();
     }
 
-    pub fn left(&self) -> Option<&'c Node<'c>> {
+    pub fn left(&self) -> Option<&'c Node<'c, T>> {
         let left = self.left.as_ref();
         left
     }
 
-    pub fn left_mut(&mut self) -> Option<&'c mut Node<'c>> {
+    pub fn left_mut(&mut self) -> Option<&'c mut Node<'c, T>> {
         self.left.as_mut()
     }
 
-    pub fn left_value(&self) -> Option<Value<'c>> {
+    pub fn left_value(&self) -> Option<T> {
         if let Some(left) = self.left() {
             left.value()
         } else {
@@ -144,17 +201,18 @@ impl<'c> Node<'c> {
         right.decr_ref();
         self.right.dealloc(true);
         self.right = UniquePointer::null();
+        self.update_ancestors();
     }
 
-    pub fn right(&self) -> Option<&'c Node<'c>> {
+    pub fn right(&self) -> Option<&'c Node<'c, T>> {
         self.right.as_ref()
     }
 
-    pub fn right_mut(&mut self) -> Option<&'c mut Node<'c>> {
+    pub fn right_mut(&mut self) -> Option<&'c mut Node<'c, T>> {
         self.right.as_mut()
     }
 
-    pub fn right_value(&self) -> Option<Value<'c>> {
+    pub fn right_value(&self) -> Option<T> {
         if let Some(right) = self.right() {
             right.value()
         } else {
@@ -162,15 +220,18 @@ impl<'c> Node<'c> {
         }
     }
 
+    /// `height` returns the number of edges in the longest path from
+    /// this node down to a leaf, as augmented in each node by
+    /// [`Node::subtree_update`].
     pub fn height(&self) -> usize {
-        let mut node = self;
-        let mut vertices = 0;
+        self.height
+    }
 
-        while !node.left.is_null() {
-            node = node.left.inner_ref();
-            vertices += 1;
-        }
-        vertices
+    /// `size` returns the number of nodes holding an item in the
+    /// subtree rooted at this node, as augmented in each node by
+    /// [`Node::subtree_update`].
+    pub fn size(&self) -> usize {
+        self.size
     }
 
     pub fn depth(&self) -> usize {
@@ -192,7 +253,7 @@ impl<'c> Node<'c> {
     }
 
     pub fn addr(&self) -> usize {
-        (self as *const Node<'c>).addr()
+        (self as *const Node<'c, T>).addr()
     }
 
     pub fn left_addr(&self) -> usize {
@@ -211,9 +272,9 @@ impl<'c> Node<'c> {
         *self.refs
     }
 
-    pub fn subtree_first(&self) -> &'c Node<'c> {
+    pub fn subtree_first(&self) -> &'c Node<'c, T> {
         if self.left.is_null() {
-            let node = self as *const Node<'c>;
+            let node = self as *const Node<'c, T>;
             return unsafe { node.as_ref().unwrap() };
         }
 
@@ -231,7 +292,7 @@ impl<'c> Node<'c> {
         unsafe { subtree_first.as_mut().unwrap() }
     }
 
-    pub fn successor(&self) -> &'c Node<'c> {
+    pub fn successor(&self) -> &'c Node<'c, T> {
         if !self.right.is_null() {
             return unsafe { self.right.as_ref().unwrap() }.subtree_first();
         }
@@ -242,7 +303,7 @@ impl<'c> Node<'c> {
                 return self.subtree_first();
             }
         }
-        let mut successor = self as *const Node<'c>;
+        let mut successor = self as *const Node<'c, T>;
         let mut node = unsafe { &*successor };
         loop {
             if node.left() == Some(self) {
@@ -258,9 +319,9 @@ impl<'c> Node<'c> {
         unsafe { &*successor }
     }
 
-    pub fn subtree_first_mut(&mut self) -> &'c mut Node<'c> {
+    pub fn subtree_first_mut(&mut self) -> &'c mut Node<'c, T> {
         if self.left.is_null() {
-            let node = self as *mut Node<'c>;
+            let node = self as *mut Node<'c, T>;
             return cast_node_mut!(node, noincr);
         }
 
@@ -279,7 +340,7 @@ impl<'c> Node<'c> {
         subtree_first.inner_mut()
     }
 
-    pub fn successor_mut(&mut self) -> &'c mut Node<'c> {
+    pub fn successor_mut(&mut self) -> &'c mut Node<'c, T> {
         if !self.right.is_null() {
             return self.right.inner_mut().subtree_first_mut();
         }
@@ -290,7 +351,7 @@ impl<'c> Node<'c> {
                 return self.subtree_first_mut();
             }
         }
-        let mut successor = self as *mut Node<'c>;
+        let mut successor = self as *mut Node<'c, T>;
         let mut node = cast_node_mut!(successor, noincr);
 
         loop {
@@ -307,82 +368,86 @@ impl<'c> Node<'c> {
         cast_node_mut!(successor, noincr)
     }
 
-    pub fn subtree_insert_after(&mut self, new: &mut Node<'c>) {
+    pub fn subtree_insert_after(&mut self, new: &mut Node<'c, T>) {
         if self.right.is_null() {
             self.set_right(new);
         } else {
             let mut successor = self.successor_mut();
             successor.set_left(new);
         }
+        new.maintain();
     }
 
-    pub fn predecessor(&self) -> &'c Node<'c> {
-        let mut predecessor = self as *const Node<'c>;
+    pub fn subtree_insert_before(&mut self, new: &mut Node<'c, T>) {
+        if self.left.is_null() {
+            self.set_left(new);
+        } else {
+            let mut predecessor = self.predecessor_mut();
+            predecessor.set_right(new);
+        }
+        new.maintain();
+    }
+
+    pub fn predecessor(&self) -> &'c Node<'c, T> {
+        if !self.left.is_null() {
+            return self.left.inner_ref().subtree_last();
+        }
+        let mut predecessor = self as *const Node<'c, T>;
         let mut node = cast_node_ref!(predecessor);
 
-        loop {
-            if !node.left.is_null() {
-                predecessor = node.left.cast_const();
-                node = cast_node_ref!(predecessor);
-                if !node.right.is_null() {
-                    predecessor = node.right.cast_const();
-                    node = cast_node_ref!(predecessor);
-                }
-                break;
-            } else if !node.parent.is_null() {
-                predecessor = node.parent.cast_const();
-                node = cast_node_ref!(predecessor);
-                if let Some(right) = node.right() {
-                    if right == self {
-                        break;
-                    }
-                }
+        while let Some(parent) = node.parent() {
+            if parent.right() == Some(node) {
+                return parent;
             }
+            predecessor = node.parent.cast_const();
+            node = cast_node_ref!(predecessor);
         }
-        node = cast_node_ref!(predecessor);
-        node
+        cast_node_ref!(self as *const Node<'c, T>)
     }
 
-    pub fn predecessor_mut(&mut self) -> &'c mut Node<'c> {
-        let mut predecessor = UniquePointer::<Node<'c>>::from_ref_mut(self);
-        let mut node = predecessor.inner_mut();
-
-        loop {
-            if !node.left.is_null() {
-                predecessor = node.left.clone();
-                node = predecessor.inner_mut();
-                if !node.right.is_null() {
-                    predecessor = node.right.clone();
-                    node = predecessor.inner_mut();
-                }
-                break;
-            } else if !node.parent.is_null() {
-                predecessor = node.parent.clone();
-                node = predecessor.inner_mut();
+    pub fn predecessor_mut(&mut self) -> &'c mut Node<'c, T> {
+        if !self.left.is_null() {
+            return self.left.inner_mut().subtree_last_mut();
+        }
+        let mut predecessor = self as *mut Node<'c, T>;
+        let mut node = cast_node_mut!(predecessor, noincr);
 
-                if let Some(right) = node.right() {
-                    if right == self {
-                        break;
-                    }
-                }
+        while !node.parent.is_null() {
+            let parent = node.parent.inner_mut();
+            if parent.right() == Some(node) {
+                return parent;
             }
+            predecessor = node.parent.cast_mut();
+            node = cast_node_mut!(predecessor, noincr);
         }
-        predecessor.inner_mut()
+        cast_node_mut!(self as *mut Node<'c, T>, noincr)
     }
 
-    pub fn disconnect(&mut self) {
-        if !self.left.is_null() {
-            unsafe {
-                let node = self.left.inner_mut();
-                decr_ref_nonzero!(node);
-            }
+    pub fn subtree_last(&self) -> &'c Node<'c, T> {
+        let mut subtree_last = self as *const Node<'c, T>;
+        let mut node = cast_node_ref!(subtree_last);
+
+        while !node.right.is_null() {
+            subtree_last = node.right.cast_const();
+            node = cast_node_ref!(subtree_last);
         }
-        if !self.right.is_null() {
-            unsafe {
-                let node = self.right.inner_mut();
-                decr_ref_nonzero!(node);
-            }
+        node
+    }
+
+    pub fn subtree_last_mut(&mut self) -> &'c mut Node<'c, T> {
+        let mut subtree_last = self as *mut Node<'c, T>;
+        let mut node = cast_node_mut!(subtree_last, noincr);
+
+        while !node.right.is_null() {
+            subtree_last = node.right.cast_mut();
+            node = cast_node_mut!(subtree_last, noincr);
         }
+        node
+    }
+
+    /// `disconnect` detaches this node, along with its subtree, from
+    /// its parent, releasing the link which owned it
+    pub fn disconnect(&mut self) {
         if !self.parent.is_null() {
             unsafe {
                 let mut parent = self.parent.inner_mut();
@@ -398,10 +463,10 @@ impl<'c> Node<'c> {
                     parent.right.dealloc(true);
                     parent.right = UniquePointer::null();
                 }
-                parent.decr_ref();
+                parent.update_ancestors();
             }
-            self.parent.dealloc(true);
-            self.parent = UniquePointer::null();
+            self.decr_ref();
+            self.parent.dealloc();
         }
     }
 
@@ -422,10 +487,7 @@ impl<'c> Node<'c> {
             //     }
             // }
         } else {
-            if !self.parent.is_null() {
-                self.parent.dealloc(true);
-                self.parent = UniquePointer::null();
-            }
+            self.parent.dealloc();
             if !self.left.is_null() {
                 self.left.dealloc(true);
                 self.left = UniquePointer::null();
@@ -441,21 +503,26 @@ impl<'c> Node<'c> {
         }
     }
 
-    pub fn swap_item(&mut self, other: &mut Node<'c>) {
-        self.item = unsafe {
-            let item = other.item.propagate();
-            other.item = self.item.propagate();
-            item
-        };
+    /// `swap_item` swaps the item of this node with that of `other`,
+    /// updating the sizes of both when only one of them has an item
+    pub fn swap_item(&mut self, other: &mut Node<'c, T>) {
+        std::mem::swap(&mut self.item, &mut other.item);
+        if self.item.is_null() != other.item.is_null() {
+            self.update_ancestors();
+            other.update_ancestors();
+        }
     }
 }
 
-pub fn subtree_delete<'c>(node: &mut Node<'c>) {
+/// `subtree_delete` removes the item of `node` from the tree by
+/// swapping it down until it reaches a leaf, then detaches that leaf
+/// from its parent and returns it.
+pub fn subtree_delete<'c, T: ListValue>(node: &mut Node<'c, T>) -> &'c mut Node<'c, T> {
     if node.leaf() {
         node.decr_ref();
         if node.parent.is_not_null() {
+            let mut parent = node.parent.inner_mut();
             unsafe {
-                let mut parent = node.parent.inner_mut();
                 let delete_left = if let Some(parents_left_child) = parent.left() {
                     parents_left_child == node
                 } else {
@@ -469,53 +536,625 @@ pub fn subtree_delete<'c>(node: &mut Node<'c>) {
                     parent.right = UniquePointer::null();
                 }
             }
-            node.parent.dealloc(true);
-            node.parent = UniquePointer::null();
+            node.parent.dealloc();
+            parent.maintain();
         } else {
             // unreachable!("leaf node {} should have a parent", node);
         }
         node.refs.reset();
-        node.parent = UniquePointer::<Node<'c>>::null();
         // node.dealloc();
-        return;
-    } else {
+        cast_node_mut!(node as *mut Node<'c, T>, noincr)
+    } else if node.left.is_not_null() {
         let mut predecessor = node.predecessor_mut();
         predecessor.swap_item(node);
-        subtree_delete(predecessor);
+        subtree_delete(predecessor)
+    } else {
+        let mut successor = node.successor_mut();
+        successor.swap_item(node);
+        subtree_delete(successor)
+    }
+}
+
+/// AVL self-balancing as described in the MIT 6.006 course
+/// material.
+///
+/// Each node augments the height of its subtree and rotations swap
+/// items rather than nodes such that the node at the top of a rotated
+/// subtree (e.g.: the root owned by a [`BinaryTree`](crate::BinaryTree))
+/// remains at the top.
+impl<'c, T: ListValue> Node<'c, T> {
+    /// `subtree_update` recomputes the augmented height and size of
+    /// this node from those of its children
+    pub fn subtree_update(&mut self) {
+        self.height = self.subtree_height();
+        self.size = self.subtree_size();
+    }
+
+    /// `skew` returns the height of the right subtree minus the
+    /// height of the left subtree, where a missing subtree has height -1
+    pub fn skew(&self) -> isize {
+        let height = |node: Option<&Node<'c, T>>| node.map(|node| node.height as isize).unwrap_or(-1);
+        height(self.right()) - height(self.left())
+    }
+
+    /// `rotate_right` rotates the subtree rooted at this node to the
+    /// right such that its left child takes its place:
+    ///
+    /// ```text
+    ///        D              B
+    ///       / \            / \
+    ///      B   E    =>    A   D
+    ///     / \                / \
+    ///    A   C              C   E
+    /// ```
+    pub fn rotate_right(&mut self) {
+        assert!(self.left.is_not_null(), "rotate_right requires a left child");
+        let node_b = self.left.inner_mut();
+        let node_a = node_b.take_left();
+        let node_c = node_b.take_right();
+        let node_e = self.take_right();
+        let node_b = self.take_left().unwrap();
+
+        std::mem::swap(&mut self.item, &mut node_b.item);
+
+        self.set_right(node_b);
+        if let Some(node_c) = node_c {
+            node_b.set_left(node_c);
+        }
+        if let Some(node_e) = node_e {
+            node_b.set_right(node_e);
+        }
+        if let Some(node_a) = node_a {
+            self.set_left(node_a);
+        }
+        node_b.subtree_update();
+        self.subtree_update();
+    }
+
+    /// `rotate_left` rotates the subtree rooted at this node to the
+    /// left such that its right child takes its place:
+    ///
+    /// ```text
+    ///      B                  D
+    ///     / \                / \
+    ///    A   D      =>      B   E
+    ///       / \            / \
+    ///      C   E          A   C
+    /// ```
+    pub fn rotate_left(&mut self) {
+        assert!(self.right.is_not_null(), "rotate_left requires a right child");
+        let node_d = self.right.inner_mut();
+        let node_c = node_d.take_left();
+        let node_e = node_d.take_right();
+        let node_a = self.take_left();
+        let node_d = self.take_right().unwrap();
+
+        std::mem::swap(&mut self.item, &mut node_d.item);
+
+        self.set_left(node_d);
+        if let Some(node_a) = node_a {
+            node_d.set_left(node_a);
+        }
+        if let Some(node_c) = node_c {
+            node_d.set_right(node_c);
+        }
+        if let Some(node_e) = node_e {
+            self.set_right(node_e);
+        }
+        node_d.subtree_update();
+        self.subtree_update();
+    }
+
+    /// `rebalance` rotates the subtree rooted at this node when its
+    /// skew is outside of the range [-1, 1]
+    pub fn rebalance(&mut self) {
+        match self.skew() {
+            2 => {
+                let right = self.right.inner_mut();
+                if right.skew() < 0 {
+                    right.rotate_right();
+                }
+                self.rotate_left();
+            },
+            -2 => {
+                let left = self.left.inner_mut();
+                if left.skew() > 0 {
+                    left.rotate_left();
+                }
+                self.rotate_right();
+            },
+            _ => {},
+        }
+    }
+
+    /// `maintain` rebalances and updates this node and each of its
+    /// ancestors up to the root
+    pub fn maintain(&mut self) {
+        let mut node = cast_node_mut!(self as *mut Node<'c, T>, noincr);
+        loop {
+            node.rebalance();
+            node.subtree_update();
+            if node.parent.is_null() {
+                break;
+            }
+            node = node.parent.inner_mut();
+        }
+    }
+
+    /// `is_avl` returns true when every node of the subtree has a
+    /// skew within [-1, 1], an up-to-date height and size and children
+    /// whose parent is the node itself
+    pub fn is_avl(&self) -> bool {
+        let mut stack = vec![cast_node_ref!(self as *const Node<'c, T>)];
+        while let Some(node) = stack.pop() {
+            if !(-1..=1).contains(&node.skew()) {
+                return false;
+            }
+            if node.subtree_height() != node.height || node.subtree_size() != node.size {
+                return false;
+            }
+            for child in [node.left(), node.right()].into_iter().flatten() {
+                if child.parent.addr() != node.addr() {
+                    return false;
+                }
+                stack.push(child);
+            }
+        }
+        true
+    }
+}
+
+/// Binary Search Tree implementation of the "Set" interface as
+/// described in the MIT 6.006 course material, keyed by the item of
+/// each [`Node`].
+///
+/// Nodes created by [`Node::insert`] are heap-allocated and
+/// [`Node::delete`] frees the node it detaches, therefore both
+/// methods should only be used in trees whose non-root nodes were
+/// created by [`Node::insert`] or [`BinaryTree::node`](crate::BinaryTree::node).
+impl<'c, T: ListValue> Node<'c, T> {
+    /// `find` returns the node whose item equals `key`
+    pub fn find(&self, key: &T) -> Option<&'c Node<'c, T>> {
+        let mut node = cast_node_ref!(self as *const Node<'c, T>);
+        loop {
+            let item = node.item.as_ref()?;
+            node = if key < item {
+                node.left()?
+            } else if key > item {
+                node.right()?
+            } else {
+                return Some(node);
+            };
+        }
+    }
+
+    /// `find_mut` returns the node whose item equals `key`
+    pub fn find_mut(&mut self, key: &T) -> Option<&'c mut Node<'c, T>> {
+        let mut node = cast_node_mut!(self as *mut Node<'c, T>, noincr);
+        loop {
+            let item = node.item.as_ref()?;
+            node = if key < item {
+                node.left_mut()?
+            } else if key > item {
+                node.right_mut()?
+            } else {
+                return Some(node);
+            };
+        }
+    }
+
+    /// `find_min` returns the node with the smallest item of the subtree
+    pub fn find_min(&self) -> Option<&'c Node<'c, T>> {
+        if self.item.is_null() {
+            None
+        } else {
+            Some(self.subtree_first())
+        }
+    }
+
+    /// `find_max` returns the node with the largest item of the subtree
+    pub fn find_max(&self) -> Option<&'c Node<'c, T>> {
+        if self.item.is_null() {
+            None
+        } else {
+            Some(self.subtree_last())
+        }
+    }
+
+    /// `find_next` returns the node with the smallest item greater than `key`
+    pub fn find_next(&self, key: &T) -> Option<&'c Node<'c, T>> {
+        if let Some(node) = self.find(key)
+            && node.right.is_not_null()
+        {
+            return Some(node.successor());
+        }
+        let mut next = None;
+        let mut node = self.item.as_ref().map(|_| cast_node_ref!(self as *const Node<'c, T>));
+        while let Some(current) = node {
+            if key < current.item.inner_ref() {
+                next = Some(current);
+                node = current.left();
+            } else {
+                node = current.right();
+            }
+        }
+        next
+    }
+
+    /// `find_prev` returns the node with the largest item smaller than `key`
+    pub fn find_prev(&self, key: &T) -> Option<&'c Node<'c, T>> {
+        if let Some(node) = self.find(key)
+            && node.left.is_not_null()
+        {
+            return Some(node.predecessor());
+        }
+        let mut prev = None;
+        let mut node = self.item.as_ref().map(|_| cast_node_ref!(self as *const Node<'c, T>));
+        while let Some(current) = node {
+            if key > current.item.inner_ref() {
+                prev = Some(current);
+                node = current.right();
+            } else {
+                node = current.left();
+            }
+        }
+        prev
+    }
+
+    /// `insert` adds `value` to the subtree, replacing the item of the
+    /// node whose item is equal to `value` if such node exists, and
+    /// returns the node that holds it.
+    ///
+    /// Rebalancing may rotate the new item above this node, therefore
+    /// the node holding it is searched from the root of the tree.
+    pub fn insert(&mut self, value: T) -> &'c mut Node<'c, T> {
+        let mut node = cast_node_mut!(self as *mut Node<'c, T>, noincr);
+        if node.item.is_null() {
+            node.item.write(value);
+            node.update_ancestors();
+            return node;
+        }
+        loop {
+            let item = node.item.inner_ref();
+            if value < *item {
+                if node.left.is_null() {
+                    let key = value.clone();
+                    node.subtree_insert_before(cast_node_mut!(Node::alloc(value), noincr));
+                    return self.root_mut().find_mut(&key).unwrap();
+                }
+                node = node.left.inner_mut();
+            } else if value > *item {
+                if node.right.is_null() {
+                    let key = value.clone();
+                    node.subtree_insert_after(cast_node_mut!(Node::alloc(value), noincr));
+                    return self.root_mut().find_mut(&key).unwrap();
+                }
+                node = node.right.inner_mut();
+            } else {
+                *node.item.inner_mut() = value;
+                return node;
+            }
+        }
+    }
+
+    /// `delete` removes the item equal to `key` from the subtree and
+    /// returns it.
+    ///
+    /// When the item is held by the node upon which `delete` was
+    /// called and that node has no children, the node is left without
+    /// an item rather than freed.
+    pub fn delete(&mut self, key: &T) -> Option<T> {
+        let node = self.find_mut(key)?;
+        Some(self.delete_node(node))
+    }
+}
+
+/// Sequence AVL implementation of the "Sequence" interface as
+/// described in the MIT 6.006 course material, where the items are
+/// ordered by their position in the in-order traversal of the tree
+/// rather than by their value.
+///
+/// Indexes are resolved in O(log n) through the subtree size
+/// augmented in each node and, like the "Set" interface, nodes are
+/// heap-allocated by [`Node::insert_at`] and freed by [`Node::delete_at`].
+impl<'c, T: ListValue> Node<'c, T> {
+    /// `subtree_at` returns the node at position `index` of the
+    /// in-order traversal of the subtree
+    pub fn subtree_at(&self, index: usize) -> Option<&'c Node<'c, T>> {
+        let mut node = cast_node_ref!(self as *const Node<'c, T>);
+        let mut index = index;
+        loop {
+            let left_size = node.left().map(Node::size).unwrap_or(0);
+            node = if index < left_size {
+                node.left()?
+            } else if index > left_size {
+                index -= left_size + 1;
+                node.right()?
+            } else {
+                return Some(node);
+            };
+        }
+    }
+
+    /// `subtree_at_mut` returns the node at position `index` of the
+    /// in-order traversal of the subtree
+    pub fn subtree_at_mut(&mut self, index: usize) -> Option<&'c mut Node<'c, T>> {
+        let mut node = cast_node_mut!(self as *mut Node<'c, T>, noincr);
+        let mut index = index;
+        loop {
+            let left_size = node.left().map(Node::size).unwrap_or(0);
+            node = if index < left_size {
+                node.left_mut()?
+            } else if index > left_size {
+                index -= left_size + 1;
+                node.right_mut()?
+            } else {
+                return Some(node);
+            };
+        }
+    }
+
+    /// `get_at` returns the item at position `index` of the subtree
+    pub fn get_at(&self, index: usize) -> Option<T> {
+        self.subtree_at(index).and_then(Node::value)
+    }
+
+    /// `set_at` replaces the item at position `index` of the subtree
+    /// with `value` and returns the previous item
+    pub fn set_at(&mut self, index: usize, value: T) -> Option<T> {
+        let node = self.subtree_at_mut(index)?;
+        let previous = node.value();
+        *node.item.inner_mut() = value;
+        previous
+    }
+
+    /// `insert_at` inserts `value` at position `index` of the subtree,
+    /// shifting the items after it, and returns the node that holds it.
+    ///
+    /// Like [`Node::insert`], `value` becomes the item of this node
+    /// when it has none.
+    ///
+    /// Rebalancing may rotate the new item above this node, therefore
+    /// the node holding it is searched from the root of the tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > self.size()`
+    pub fn insert_at(&mut self, index: usize, value: T) -> &'c mut Node<'c, T> {
+        let size = self.size();
+        assert!(index <= size, "insertion index (is {index}) should be <= size (is {size})");
+        if self.item.is_null() {
+            self.item.write(value);
+            self.update_ancestors();
+            return cast_node_mut!(self as *mut Node<'c, T>, noincr);
+        }
+        let offset = self.index_of() - self.left().map(Node::size).unwrap_or(0);
+        let new = cast_node_mut!(Node::alloc(value), noincr);
+        if index == 0 {
+            self.subtree_first_mut().subtree_insert_before(new);
+        } else {
+            self.subtree_at_mut(index - 1).unwrap().subtree_insert_after(new);
+        }
+        self.root_mut().subtree_at_mut(offset + index).unwrap()
+    }
+
+    /// `delete_at` removes the item at position `index` of the
+    /// subtree, shifting the items after it, and returns it.
+    ///
+    /// Like [`Node::delete`], the node upon which `delete_at` was
+    /// called is left without an item rather than freed when it holds
+    /// the last item of the subtree.
+    pub fn delete_at(&mut self, index: usize) -> Option<T> {
+        let node = self.subtree_at_mut(index)?;
+        Some(self.delete_node(node))
+    }
+
+    /// `index_of` returns the position of this node in the in-order
+    /// traversal of the whole tree it belongs to
+    pub fn index_of(&self) -> usize {
+        let mut index = self.left().map(Node::size).unwrap_or(0);
+        let mut node = cast_node_ref!(self as *const Node<'c, T>);
+        while let Some(parent) = node.parent() {
+            if parent.right.addr() == node.addr() {
+                index += parent.left().map(Node::size).unwrap_or(0) + 1;
+            }
+            node = parent;
+        }
+        index
+    }
+}
+
+/// Traversals of the subtree rooted at a [`Node`], which never
+/// leave that subtree even when the node has a parent.
+///
+/// The iterators read the links of each node without changing any
+/// [`RefCounter`]. The mutable variants are meant for changing the
+/// items of the visited nodes: relinking nodes while iterating leaves
+/// the traversal in an unspecified order.
+impl<'c, T: ListValue> Node<'c, T> {
+    /// `iter_inorder` iterates over the nodes of the subtree in the
+    /// order left, node, right
+    pub fn iter_inorder(&self) -> InOrder<'c, T> {
+        InOrder::new(self as *const Node<'c, T> as *mut Node<'c, T>)
+    }
+
+    /// `iter_preorder` iterates over the nodes of the subtree in the
+    /// order node, left, right
+    pub fn iter_preorder(&self) -> PreOrder<'c, T> {
+        PreOrder::new(self as *const Node<'c, T> as *mut Node<'c, T>)
+    }
+
+    /// `iter_postorder` iterates over the nodes of the subtree in the
+    /// order left, right, node
+    pub fn iter_postorder(&self) -> PostOrder<'c, T> {
+        PostOrder::new(self as *const Node<'c, T> as *mut Node<'c, T>)
+    }
+
+    /// `iter_level_order` iterates over the nodes of the subtree
+    /// breadth-first, from left to right within each level
+    pub fn iter_level_order(&self) -> LevelOrder<'c, T> {
+        LevelOrder::new(self as *const Node<'c, T> as *mut Node<'c, T>)
+    }
+
+    /// `iter_inorder_mut` is the mutable variant of [`Node::iter_inorder`]
+    pub fn iter_inorder_mut(&mut self) -> InOrderMut<'c, T> {
+        InOrderMut::new(self as *mut Node<'c, T>)
+    }
+
+    /// `iter_preorder_mut` is the mutable variant of [`Node::iter_preorder`]
+    pub fn iter_preorder_mut(&mut self) -> PreOrderMut<'c, T> {
+        PreOrderMut::new(self as *mut Node<'c, T>)
+    }
+
+    /// `iter_postorder_mut` is the mutable variant of [`Node::iter_postorder`]
+    pub fn iter_postorder_mut(&mut self) -> PostOrderMut<'c, T> {
+        PostOrderMut::new(self as *mut Node<'c, T>)
+    }
+
+    /// `iter_level_order_mut` is the mutable variant of [`Node::iter_level_order`]
+    pub fn iter_level_order_mut(&mut self) -> LevelOrderMut<'c, T> {
+        LevelOrderMut::new(self as *mut Node<'c, T>)
     }
 }
 
 /// Node private methods
-impl<'c> Node<'c> {
-    pub fn ptr(&self) -> UniquePointer<Node<'c>> {
-        let ptr =
-            UniquePointer::copy_from_ref(self, *self.refs, UniquePointer::raw_addr_of_ref(self));
-        ptr
+impl<'c, T: ListValue> Node<'c, T> {
+    /// `ptr` creates the link to this node held by its parent, which
+    /// shares the [`RefCounter`] of this node rather than allocating
+    /// its own, see [`UniquePointer::borrowed`]
+    pub fn ptr(&self) -> UniquePointer<Node<'c, T>> {
+        UniquePointer::borrowed(self as *const Node<'c, T> as *mut Node<'c, T>, &self.refs)
     }
 
-    fn incr_ref(&mut self) {
-        self.refs += 1;
+    /// `weak_ptr` creates a [`WeakPointer`] to this node sharing its
+    /// [`RefCounter`], such that the children which point to this
+    /// node as their parent observe when it is freed
+    fn weak_ptr(&self) -> WeakPointer<Node<'c, T>> {
+        WeakPointer::new(self as *const Node<'c, T> as *mut Node<'c, T>, &self.refs)
+    }
+
+    /// `subtree_height` computes the height of this node from the
+    /// augmented heights of its children
+    fn subtree_height(&self) -> usize {
+        match (self.left(), self.right()) {
+            (None, None) => 0,
+            (Some(left), None) => left.height + 1,
+            (None, Some(right)) => right.height + 1,
+            (Some(left), Some(right)) => left.height.max(right.height) + 1,
+        }
+    }
+
+    /// `subtree_size` computes the size of this node from the
+    /// augmented sizes of its children, counting this node only when
+    /// it holds an item
+    fn subtree_size(&self) -> usize {
+        self.item.is_not_null() as usize
+            + self.left().map(Node::size).unwrap_or(0)
+            + self.right().map(Node::size).unwrap_or(0)
+    }
+
+    /// `delete_node` calls [`subtree_delete`] on `node` of this
+    /// subtree, frees the leaf it detaches unless that is this node,
+    /// and returns the item of `node`.
+    fn delete_node(&mut self, node: &mut Node<'c, T>) -> T {
+        let value = node.item.inner_ref().clone();
+        let leaf = subtree_delete(node);
+        if leaf.addr() == self.addr() {
+            self.item.dealloc(true);
+            self.item = UniquePointer::null();
+            self.update_ancestors();
+        } else {
+            unsafe { Node::free(leaf) }
+        }
+        value
+    }
+
+    /// `root_mut` returns the root of the tree this node belongs to
+    fn root_mut(&mut self) -> &'c mut Node<'c, T> {
+        let mut node = cast_node_mut!(self as *mut Node<'c, T>, noincr);
+        while let Some(parent) = node.parent.as_mut() {
+            node = parent;
+        }
+        node
+    }
+
+    /// `update_ancestors` calls [`Node::subtree_update`] on this node
+    /// and each of its ancestors up to the root
+    fn update_ancestors(&mut self) {
         let mut node = self;
-        while !node.parent.is_null() {
-            unsafe {
-                node = node.parent.peek_mut();
-                node.refs += 1;
+        loop {
+            node.subtree_update();
+            if node.parent.is_null() {
+                break;
             }
+            node = node.parent.inner_mut();
         }
     }
 
+    /// `take_left` detaches the left child of this node undoing the
+    /// references counted in [`Node::set_left`]
+    fn take_left(&mut self) -> Option<&'c mut Node<'c, T>> {
+        let left = self.left_mut()?;
+        left.decr_ref();
+        if left.parent.addr() == self.addr() {
+            left.parent.dealloc();
+        }
+        self.left.dealloc(true);
+        Some(left)
+    }
+
+    /// `take_right` detaches the right child of this node undoing the
+    /// references counted in [`Node::set_right`]
+    fn take_right(&mut self) -> Option<&'c mut Node<'c, T>> {
+        let right = self.right_mut()?;
+        right.decr_ref();
+        if right.parent.addr() == self.addr() {
+            right.parent.dealloc();
+        }
+        self.right.dealloc(true);
+        Some(right)
+    }
+
+    /// `incr_ref` increments the references of this node.
+    ///
+    /// Only the links which own this node count, i.e.: the link from
+    /// its parent, as the children point to it through a
+    /// [`WeakPointer`], such that the references of a node do not
+    /// depend on its descendants.
+    fn incr_ref(&mut self) {
+        self.refs += 1;
+    }
+
+    /// `decr_ref` decrements the references of this node, see
+    /// [`Node::incr_ref`]
     fn decr_ref(&mut self) {
         decr_ref_nonzero!(self);
-        let mut node = self;
-        while !node.parent.is_null() {
-            unsafe {
-                node = node.parent.inner_mut();
-                decr_ref_nonzero!(node);
-            }
+    }
+
+    /// `alloc` allocates a detached node holding `value` on the heap
+    pub(crate) fn alloc(value: T) -> *mut Node<'c, T> {
+        unsafe {
+            let node = internal::alloc::node();
+            node.write(Node::new(value));
+            node
         }
     }
 
-    fn item_eq(&self, other: &Node<'c>) -> bool {
+    /// `free` releases the memory of a node allocated via
+    /// [`Node::alloc`] along with its item, reference counter and
+    /// the links to its children. The nodes it links to are left
+    /// untouched.
+    pub(crate) unsafe fn free(node: *mut Node<'c, T>) {
+        let node_ref = cast_node_mut!(node, noincr);
+        node_ref.parent.dealloc();
+        node_ref.left.dealloc(true);
+        node_ref.right.dealloc(true);
+        node_ref.item.dealloc(true);
+        node_ref.refs.dealloc();
+        unsafe { internal::dealloc::node(node) }
+    }
+
+    fn item_eq(&self, other: &Node<'c, T>) -> bool {
         if self.item.addr() == other.item.addr() {
             self.item.addr() == other.item.addr()
         } else {
@@ -523,7 +1162,7 @@ impl<'c> Node<'c> {
         }
     }
 
-    fn left_eq(&self, other: &Node<'c>) -> bool {
+    fn left_eq(&self, other: &Node<'c, T>) -> bool {
         if self.left.addr() == other.left.addr() {
             self.left.addr() == other.left.addr()
         } else {
@@ -531,7 +1170,7 @@ impl<'c> Node<'c> {
         }
     }
 
-    fn right_eq(&self, other: &Node<'c>) -> bool {
+    fn right_eq(&self, other: &Node<'c, T>) -> bool {
         if self.right.addr() == other.right.addr() {
             self.right.addr() == other.right.addr()
         } else {
@@ -539,7 +1178,7 @@ impl<'c> Node<'c> {
         }
     }
 
-    fn parent_eq(&self, other: &Node<'c>) -> bool {
+    fn parent_eq(&self, other: &Node<'c, T>) -> bool {
         if self.parent.addr() == other.parent.addr() {
             self.parent.addr() == other.parent.addr()
         } else {
@@ -548,10 +1187,10 @@ impl<'c> Node<'c> {
     }
 }
 
-impl<'c> PartialEq<Node<'c>> for Node<'c> {
-    fn eq(&self, other: &Node<'c>) -> bool {
+impl<'c, T: ListValue> PartialEq<Node<'c, T>> for Node<'c, T> {
+    fn eq(&self, other: &Node<'c, T>) -> bool {
         if self.item_eq(other) {
-            let eq = self.value().unwrap_or_default() == other.value().unwrap_or_default();
+            let eq = self.value() == other.value();
             eq
         } else {
             false
@@ -559,11 +1198,11 @@ impl<'c> PartialEq<Node<'c>> for Node<'c> {
     }
 }
 
-impl<'c> PartialEq<&mut Node<'c>> for Node<'c> {
-    fn eq(&self, other: &&mut Node<'c>) -> bool {
+impl<'c, T: ListValue> PartialEq<&mut Node<'c, T>> for Node<'c, T> {
+    fn eq(&self, other: &&mut Node<'c, T>) -> bool {
         let other = unsafe { &**other };
         if self.item_eq(other) {
-            let eq = self.value().unwrap_or_default() == other.value().unwrap_or_default();
+            let eq = self.value() == other.value();
             eq
         } else {
             false
@@ -578,18 +1217,20 @@ impl<'c> PartialEq<&mut Node<'c>> for Node<'c> {
 //     }
 // }
 
-impl<'c> Clone for Node<'c> {
-    fn clone(&self) -> Node<'c> {
-        let mut node = Node::nil();
+impl<'c, T: ListValue> Clone for Node<'c, T> {
+    fn clone(&self) -> Node<'c, T> {
+        let mut node = Node::empty();
         node.refs = self.refs.clone();
+        node.height = self.height;
+        node.size = self.size;
         if self.parent.is_not_null() {
             node.parent = self.parent.clone();
         }
-        if self.left.is_not_null() {
-            node.left = self.left.clone();
+        if let Some(left) = self.left() {
+            node.left = left.ptr();
         }
-        if self.right.is_not_null() {
-            node.right = self.right.clone();
+        if let Some(right) = self.right() {
+            node.right = right.ptr();
         }
         if !self.item.is_null() {
             node.item = self.item.clone();
@@ -598,22 +1239,22 @@ impl<'c> Clone for Node<'c> {
     }
 }
 
-impl<'c> AsRef<Node<'c>> for Node<'c> {
-    fn as_ref(&self) -> &'c Node<'c> {
-        cast_node_ref!(self as *const Node<'c>)
+impl<'c, T: ListValue> AsRef<Node<'c, T>> for Node<'c, T> {
+    fn as_ref(&self) -> &'c Node<'c, T> {
+        cast_node_ref!(self as *const Node<'c, T>)
     }
 }
-impl<'c> AsMut<Node<'c>> for Node<'c> {
-    fn as_mut(&mut self) -> &'c mut Node<'c> {
-        cast_node_mut!(self as *mut Node<'c>, incr)
+impl<'c, T: ListValue> AsMut<Node<'c, T>> for Node<'c, T> {
+    fn as_mut(&mut self) -> &'c mut Node<'c, T> {
+        cast_node_mut!(self as *mut Node<'c, T>, incr)
     }
 }
-impl<'c> std::fmt::Display for Node<'c> {
+impl<'c, T: ListValue> std::fmt::Display for Node<'c, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}", self.id())
     }
 }
-impl<'c> std::fmt::Debug for Node<'c> {
+impl<'c, T: ListValue> std::fmt::Debug for Node<'c, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
             f,
@@ -0,0 +1,154 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::{Value, ValueError};
+
+/// `Number` is the numeric content of a [`Value`] as used by the
+/// arithmetic operators: integers are widened to `i128` along with
+/// the `rank` of their variant in the numeric tower
+/// `Byte → UInt → Int`, above which sits `Float`.
+#[derive(Clone, Copy)]
+enum Number {
+    Integer(i128, usize),
+    Float(f64),
+}
+
+const BYTE: usize = 0;
+const UINT: usize = 1;
+const INT: usize = 2;
+
+impl Number {
+    fn of(value: &Value<'_>) -> Result<Number, ValueError> {
+        match value {
+            Value::Byte(n) => Ok(Number::Integer(i128::from(*n), BYTE)),
+            Value::UInt(n) => Ok(Number::Integer(i128::from(*n), UINT)),
+            Value::Int(n) => Ok(Number::Integer(i128::from(*n), INT)),
+            Value::Float(n) => Ok(Number::Float(*n)),
+            value => Err(ValueError::NotNumeric(format!("{:?}", value))),
+        }
+    }
+
+    fn float(self) -> f64 {
+        match self {
+            Number::Integer(n, _) => n as f64,
+            Number::Float(n) => n,
+        }
+    }
+
+    /// `integer` returns `n` in the variant of the lowest rank not
+    /// below `rank` that holds it, or else in the variant of the
+    /// lowest rank from `lowest` that holds it, such that the result
+    /// of mixing [`Value::UInt`] and [`Value::Int`] may be either.
+    fn integer<'c>(n: Option<i128>, lowest: usize, rank: usize) -> Result<Value<'c>, ValueError> {
+        let n = n.ok_or(ValueError::Overflow)?;
+        (rank..=INT)
+            .chain(lowest..rank)
+            .find_map(|rank| match rank {
+                BYTE => u8::try_from(n).ok().map(Value::Byte),
+                UINT => u64::try_from(n).ok().map(Value::UInt),
+                _ => i64::try_from(n).ok().map(Value::Int),
+            })
+            .ok_or(ValueError::Overflow)
+    }
+
+    /// `apply` applies the integer operation `integer` to `lhs` and
+    /// `rhs` when both are integers, or else the floating point
+    /// operation `float`
+    fn apply<'c>(
+        lhs: &Value<'_>,
+        rhs: &Value<'_>,
+        integer: fn(i128, i128) -> Result<Option<i128>, ValueError>,
+        float: fn(f64, f64) -> f64,
+    ) -> Result<Value<'c>, ValueError> {
+        match (Number::of(lhs)?, Number::of(rhs)?) {
+            (Number::Integer(a, a_rank), Number::Integer(b, b_rank)) => {
+                Number::integer(integer(a, b)?, a_rank.min(b_rank), a_rank.max(b_rank))
+            },
+            (a, b) => Ok(Value::Float(float(a.float(), b.float()))),
+        }
+    }
+}
+
+fn nonzero(n: i128) -> Result<i128, ValueError> {
+    if n == 0 {
+        Err(ValueError::DivisionByZero)
+    } else {
+        Ok(n)
+    }
+}
+
+macro_rules! binary_operator {
+    ($trait:ident, $method:ident, $integer:expr, $float:expr) => {
+        impl<'c> $trait<Value<'c>> for Value<'c> {
+            type Output = Result<Value<'c>, ValueError>;
+
+            fn $method(self, rhs: Value<'c>) -> Result<Value<'c>, ValueError> {
+                Number::apply(&self, &rhs, $integer, $float)
+            }
+        }
+        impl<'c> $trait<&Value<'c>> for &Value<'c> {
+            type Output = Result<Value<'c>, ValueError>;
+
+            fn $method(self, rhs: &Value<'c>) -> Result<Value<'c>, ValueError> {
+                Number::apply(self, rhs, $integer, $float)
+            }
+        }
+    };
+}
+
+binary_operator!(Add, add, |a, b| Ok(a.checked_add(b)), |a, b| a + b);
+binary_operator!(Sub, sub, |a, b| Ok(a.checked_sub(b)), |a, b| a - b);
+binary_operator!(Mul, mul, |a, b| Ok(a.checked_mul(b)), |a, b| a * b);
+binary_operator!(Div, div, |a, b| Ok(a.checked_div(nonzero(b)?)), |a, b| a / b);
+binary_operator!(Rem, rem, |a, b| Ok(a.checked_rem(nonzero(b)?)), |a, b| a % b);
+
+impl<'c> Neg for Value<'c> {
+    type Output = Result<Value<'c>, ValueError>;
+
+    fn neg(self) -> Result<Value<'c>, ValueError> {
+        -&self
+    }
+}
+impl<'c> Neg for &Value<'c> {
+    type Output = Result<Value<'c>, ValueError>;
+
+    fn neg(self) -> Result<Value<'c>, ValueError> {
+        match Number::of(self)? {
+            Number::Integer(n, rank) => Number::integer(n.checked_neg(), rank, rank),
+            Number::Float(n) => Ok(Value::Float(-n)),
+        }
+    }
+}
+
+impl<'c> Value<'c> {
+    /// `is_number` tells whether this value is a [`Value::Byte`],
+    /// [`Value::UInt`], [`Value::Int`] or [`Value::Float`]
+    pub fn is_number(&self) -> bool {
+        Number::of(self).is_ok()
+    }
+
+    /// `numeric_cmp` compares two numbers by their value regardless
+    /// of their variants, unlike [`Value`]'s [`Ord`] implementation,
+    /// such that `Value::Int(1) < Value::Byte(200)`.
+    ///
+    /// Integers are compared exactly while comparisons involving a
+    /// [`Value::Float`] convert the integer to `f64`. Returns `None`
+    /// when either value is not a number or is `NaN`.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use ds::Value;
+    ///
+    /// assert_eq!(Value::Int(1).numeric_cmp(&Value::Byte(200)), Some(Ordering::Less));
+    /// assert_eq!(Value::UInt(2).numeric_cmp(&Value::Float(2.0)), Some(Ordering::Equal));
+    /// assert_eq!(Value::from("2").numeric_cmp(&Value::Byte(2)), None);
+    /// ```
+    pub fn numeric_cmp(&self, other: &Value<'_>) -> Option<Ordering> {
+        match (Number::of(self).ok()?, Number::of(other).ok()?) {
+            (Number::Integer(a, _), Number::Integer(b, _)) => Some(a.cmp(&b)),
+            (a, b) => a.float().partial_cmp(&b.float()),
+        }
+    }
+}
@@ -11,14 +11,26 @@ use std::ops::{AddAssign, Deref, DerefMut, SubAssign};
 /// the hood to allow writing in non-mut references in strategic
 /// occasions such as incrementing its reference count within its
 /// [`Clone`] implementation.
+///
+/// Besides the "strong" count read by [`read`], a [`RefCounter`]
+/// keeps a separate "weak" count of the [`WeakPointer`](crate::WeakPointer)s
+/// observing it, such that its memory outlives [`dealloc`] until the
+/// last weak reference is released via [`dealloc_weak`].
 pub struct RefCounter {
-    data: *mut usize,
+    data: *mut Counts,
+}
+
+/// `Counts` is the memory shared across clones of a [`RefCounter`]
+struct Counts {
+    strong: usize,
+    weak: usize,
+    released: bool,
 }
 
 impl RefCounter {
     pub fn null() -> RefCounter {
         RefCounter {
-            data: std::ptr::null_mut::<usize>(),
+            data: std::ptr::null_mut::<Counts>(),
         }
     }
 
@@ -28,6 +40,10 @@ impl RefCounter {
         ref_counter
     }
 
+    pub fn is_null(&self) -> bool {
+        self.data.is_null()
+    }
+
     pub fn reset(&mut self) {
         self.write(1);
     }
@@ -51,12 +67,57 @@ impl RefCounter {
         }
     }
 
+    /// `incr_weak` increments the weak count, which is left
+    /// untouched when the counter is NULL
+    pub fn incr_weak(&mut self) {
+        self.incr_weak_by(1);
+    }
+
+    /// `incr_weak_by` increments the weak count by `by`, see [`incr_weak`]
+    pub fn incr_weak_by(&mut self, by: usize) {
+        if let Some(counts) = self.counts_mut() {
+            counts.weak += by;
+        }
+    }
+
+    /// `decr_weak` decrements the weak count unless it is zero
+    pub fn decr_weak(&mut self) {
+        if let Some(counts) = self.counts_mut() {
+            counts.weak = counts.weak.saturating_sub(1);
+        }
+    }
+
+    /// `dealloc` releases the strong count of the counter.
+    ///
+    /// Clones of a [`RefCounter`] share the same memory, so only the
+    /// owner of the counter should call [`dealloc`], after which
+    /// reading from any of its clones yields zero as long as a weak
+    /// reference keeps the memory around, otherwise the memory is
+    /// released immediately. Either way this counter becomes NULL.
     pub fn dealloc(&mut self) {
-        if !self.data.is_null() {
-            unsafe {
-                self.data.drop_in_place();
+        if let Some(counts) = self.counts_mut() {
+            counts.strong = 0;
+            counts.released = true;
+            if counts.weak == 0 {
+                self.free();
             }
         }
+        self.data = std::ptr::null_mut::<Counts>();
+    }
+
+    /// `dealloc_weak` releases a weak reference to the counter, see
+    /// [`decr_weak`], and frees its memory when that was the last
+    /// weak reference to a counter already released via [`dealloc`].
+    /// This counter becomes NULL.
+    pub fn dealloc_weak(&mut self) {
+        self.decr_weak();
+        if let Some(counts) = self.counts_mut()
+            && counts.released
+            && counts.weak == 0
+        {
+            self.free();
+        }
+        self.data = std::ptr::null_mut::<Counts>();
     }
 
     pub fn read(&self) -> usize {
@@ -68,22 +129,52 @@ impl RefCounter {
         }
     }
 
+    /// `strong` returns the strong count, same as [`read`]
+    pub fn strong(&self) -> usize {
+        self.read()
+    }
+
+    /// `weak` returns the weak count
+    pub fn weak(&self) -> usize {
+        self.counts().map(|counts| counts.weak).unwrap_or(0)
+    }
+
+    /// `is_released` returns true once the strong count of a counter
+    /// kept around by weak references has been released via [`dealloc`]
+    pub fn is_released(&self) -> bool {
+        self.counts().map(|counts| counts.released).unwrap_or(false)
+    }
+
     fn alloc(&self) {
         if !self.data.is_null() {
             return;
         }
 
-        let layout = Layout::new::<usize>();
+        let layout = Layout::new::<Counts>();
         let ptr = unsafe {
             let ptr = std::alloc::alloc(layout);
             if ptr.is_null() {
                 std::alloc::handle_alloc_error(layout);
             }
-            ptr as *mut usize
+            ptr as *mut Counts
         };
+        unsafe {
+            ptr.write(Counts {
+                strong: 1,
+                weak: 0,
+                released: false,
+            });
+        }
         let mut up = unsafe { self.meta_mut() };
         up.data = ptr;
-        up.write(1);
+    }
+
+    fn free(&mut self) {
+        let layout = Layout::new::<Counts>();
+        unsafe {
+            std::alloc::dealloc(self.data as *mut u8, layout);
+        }
+        self.data = std::ptr::null_mut::<Counts>();
     }
 
     fn write(&self, data: usize) {
@@ -95,12 +186,20 @@ impl RefCounter {
         }
     }
 
+    fn counts(&self) -> Option<&Counts> {
+        unsafe { self.data.as_ref() }
+    }
+
+    fn counts_mut(&mut self) -> Option<&mut Counts> {
+        unsafe { self.data.as_mut() }
+    }
+
     fn cast_mut(&self) -> *mut usize {
-        self.data
+        unsafe { &raw mut (*self.data).strong }
     }
 
     fn cast_const(&self) -> *const usize {
-        self.data.cast_const()
+        self.cast_mut().cast_const()
     }
 
     fn inner_ref<'c>(&self) -> &'c usize {
@@ -114,7 +213,7 @@ impl RefCounter {
 
     fn inner_mut<'c>(&self) -> &'c mut usize {
         if self.data.is_null() {
-            panic!("uninitialized");
+            panic!("{:#?} {}", self, crate::PointerError::Null);
         }
         let mut ptr = self.cast_mut();
         unsafe { std::mem::transmute::<&mut usize, &'c mut usize>(&mut *ptr) }
@@ -137,15 +236,13 @@ impl Deref for RefCounter {
 
 impl Drop for RefCounter {
     fn drop(&mut self) {
-        self.dealloc()
+        // clones share the same counter, see [`RefCounter::dealloc`]
     }
 }
 
 impl Clone for RefCounter {
     fn clone(&self) -> RefCounter {
-        let mut clone = RefCounter::new();
-        clone.data = self.data;
-        clone
+        RefCounter { data: self.data }
     }
 }
 
@@ -159,6 +256,7 @@ impl std::fmt::Debug for RefCounter {
                     crate::color::fg("RefCounter@", 231),
                     format!("{:016x}", self.data.addr()),
                     format!("[data={}]", self.read()),
+                    format!("[weak={}]", self.weak()),
                 ]
                 .join("")
             )
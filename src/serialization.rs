@@ -0,0 +1,382 @@
+//! `serialization` implements [`Serialize`] and [`Deserialize`] for
+//! [`Value`], [`Cell`], [`Node`] and [`BinaryTree`] behind the `serde`
+//! feature.
+//!
+//! [`Value`] is untagged, that is, each variant maps to the closest
+//! type of the serde data model: `nil` to unit, strings and symbols
+//! to strings, lists to sequences and so on. Untagged values read
+//! back as the variant the format reports, such that symbols read as
+//! strings and, with formats like JSON that do not keep the width of
+//! integers, non-negative integers read as [`Value::UInt`] and
+//! negative ones as [`Value::Int`].
+//!
+//! [`Tagged`] and the [`tagged`] module serialize [`Value`] as an
+//! externally tagged enum instead, e.g.: `{"Byte":42}`, which keeps
+//! the variant of every value.
+//!
+//! [`Cell`] is a sequence of untagged values, such that improper
+//! lists (see [`Cell::end`]) fail to serialize, and [`Node`] a map with
+//! the keys `value`, `left` and `right`, where missing children are
+//! `None`. A [`BinaryTree`] is its root node, or `None` when empty,
+//! and deserializes into the same shape without rebalancing.
+//!
+//! Example
+//!
+//! ```
+//! use ds::{list, Cell};
+//!
+//! let cell = list!("a", 1000u64, ["b"]);
+//! let json = serde_json::to_string(&cell).unwrap();
+//! assert_eq!(json, r#"["a",1000,["b"]]"#);
+//! assert_eq!(serde_json::from_str::<Cell>(&json).unwrap(), cell);
+//! ```
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{self, Deserializer, EnumAccess, SeqAccess, VariantAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::{symbol, BinaryTree, Cell, ListValue, Node, Value, ValueKind};
+
+impl Serialize for Value<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Nil => serializer.serialize_unit(),
+            Value::String(h) => serializer.serialize_str(h),
+            Value::Symbol(h) => serializer.serialize_str(h.name()),
+            Value::Byte(h) => serializer.serialize_u8(*h),
+            Value::UInt(h) => serializer.serialize_u64(*h),
+            Value::Int(h) => serializer.serialize_i64(*h),
+            Value::Float(h) => serializer.serialize_f64(*h),
+            Value::Bool(h) => serializer.serialize_bool(*h),
+            Value::Char(h) => serializer.serialize_char(*h),
+            Value::List(h) => h.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, 'c> Deserialize<'de> for Value<'c> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Value<'c>, D::Error> {
+        deserializer.deserialize_any(ValueVisitor(PhantomData))
+    }
+}
+
+/// `ValueVisitor` builds an untagged [`Value`] of any lifetime, as
+/// deserialized strings are always owned
+struct ValueVisitor<'c>(PhantomData<fn() -> Value<'c>>);
+
+impl<'de, 'c> Visitor<'de> for ValueVisitor<'c> {
+    type Value = Value<'c>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("nil, a string, number, boolean, character or sequence")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Value<'c>, E> {
+        Ok(Value::Nil)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Value<'c>, E> {
+        Ok(Value::Nil)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value<'c>, D::Error> {
+        deserializer.deserialize_any(ValueVisitor(PhantomData))
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Value<'c>, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_u8<E: de::Error>(self, value: u8) -> Result<Value<'c>, E> {
+        Ok(Value::Byte(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Value<'c>, E> {
+        Ok(Value::UInt(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Value<'c>, E> {
+        Ok(Value::Int(value))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Value<'c>, E> {
+        Ok(Value::Float(value))
+    }
+
+    fn visit_char<E: de::Error>(self, value: char) -> Result<Value<'c>, E> {
+        Ok(Value::Char(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Value<'c>, E> {
+        Ok(Value::String(Cow::Owned(value.to_string())))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Value<'c>, E> {
+        Ok(Value::String(Cow::Owned(value)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Value<'c>, A::Error> {
+        CellVisitor(PhantomData).visit_seq(seq).map(Value::List)
+    }
+}
+
+/// `CellVisitor` builds a [`Cell`] of any lifetime out of a sequence,
+/// rejecting every other type
+struct CellVisitor<'c>(PhantomData<fn() -> Cell<'c>>);
+
+impl<'de, 'c> Visitor<'de> for CellVisitor<'c> {
+    type Value = Cell<'c>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Cell<'c>, A::Error> {
+        let mut values = Vec::<Value>::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(values.into_iter().collect())
+    }
+}
+
+impl Serialize for Cell<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.end().is_some() {
+            return Err(serde::ser::Error::custom("improper lists cannot be serialized"));
+        }
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, 'c> Deserialize<'de> for Cell<'c> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Cell<'c>, D::Error> {
+        deserializer.deserialize_seq(CellVisitor(PhantomData))
+    }
+}
+
+const VARIANTS: &[&str] = &[
+    "Nil", "String", "Symbol", "Byte", "UInt", "Int", "Float", "Bool", "Char", "List",
+];
+
+impl Serialize for ValueKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let index = *self as u32;
+        serializer.serialize_unit_variant("ValueKind", index, VARIANTS[index as usize])
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ValueKind, D::Error> {
+        deserializer.deserialize_identifier(KindVisitor)
+    }
+}
+
+struct KindVisitor;
+
+impl KindVisitor {
+    const KINDS: [ValueKind; 10] = [
+        ValueKind::Nil,
+        ValueKind::String,
+        ValueKind::Symbol,
+        ValueKind::Byte,
+        ValueKind::UInt,
+        ValueKind::Int,
+        ValueKind::Float,
+        ValueKind::Bool,
+        ValueKind::Char,
+        ValueKind::List,
+    ];
+}
+
+impl<'de> Visitor<'de> for KindVisitor {
+    type Value = ValueKind;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("the name of a variant of Value")
+    }
+
+    fn visit_u64<E: de::Error>(self, index: u64) -> Result<ValueKind, E> {
+        KindVisitor::KINDS.get(index as usize).copied().ok_or_else(|| {
+            E::invalid_value(de::Unexpected::Unsigned(index), &"a variant index below 10")
+        })
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<ValueKind, E> {
+        match VARIANTS.iter().position(|variant| *variant == name) {
+            Some(index) => Ok(KindVisitor::KINDS[index]),
+            None => Err(E::unknown_variant(name, VARIANTS)),
+        }
+    }
+}
+
+/// `Tagged` wraps a [`Value`] to serialize it as an externally tagged
+/// enum, named after the variants of [`Value`], which deserializes
+/// back into the same variant, see the [`tagged`] module for use
+/// with `#[serde(with = "ds::serialization::tagged")]`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tagged<'c>(pub Value<'c>);
+
+/// `tagged` serializes a [`Value`] field as [`Tagged`]
+pub mod tagged {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{Tagged, TaggedRef};
+    use crate::Value;
+
+    pub fn serialize<S: Serializer>(value: &Value<'_>, serializer: S) -> Result<S::Ok, S::Error> {
+        TaggedRef(value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, 'c, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Value<'c>, D::Error> {
+        Tagged::deserialize(deserializer).map(|tagged| tagged.0)
+    }
+}
+
+struct TaggedRef<'a, 'c>(&'a Value<'c>);
+
+impl Serialize for TaggedRef<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let index = self.0.kind() as u32;
+        let variant = VARIANTS[index as usize];
+        match self.0 {
+            Value::Nil => serializer.serialize_unit_variant("Value", index, variant),
+            Value::List(cell) => {
+                let mut seq = TaggedSeq::default();
+                seq.0.extend(cell.iter());
+                serializer.serialize_newtype_variant("Value", index, variant, &seq)
+            },
+            value => serializer.serialize_newtype_variant("Value", index, variant, value),
+        }
+    }
+}
+
+#[derive(Default)]
+struct TaggedSeq<'a, 'c>(Vec<&'a Value<'c>>);
+
+impl Serialize for TaggedSeq<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for value in &self.0 {
+            seq.serialize_element(&TaggedRef(value))?;
+        }
+        seq.end()
+    }
+}
+
+impl Serialize for Tagged<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TaggedRef(&self.0).serialize(serializer)
+    }
+}
+
+impl<'de, 'c> Deserialize<'de> for Tagged<'c> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Tagged<'c>, D::Error> {
+        deserializer.deserialize_enum("Value", VARIANTS, TaggedVisitor(PhantomData))
+    }
+}
+
+struct TaggedVisitor<'c>(PhantomData<fn() -> Tagged<'c>>);
+
+impl<'de, 'c> Visitor<'de> for TaggedVisitor<'c> {
+    type Value = Tagged<'c>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a tagged Value")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Tagged<'c>, A::Error> {
+        let (kind, variant) = data.variant::<ValueKind>()?;
+        Ok(Tagged(match kind {
+            ValueKind::Nil => {
+                variant.unit_variant()?;
+                Value::Nil
+            },
+            ValueKind::String => Value::String(Cow::Owned(variant.newtype_variant::<String>()?)),
+            ValueKind::Symbol => Value::Symbol(symbol(&variant.newtype_variant::<String>()?)),
+            ValueKind::Byte => Value::Byte(variant.newtype_variant()?),
+            ValueKind::UInt => Value::UInt(variant.newtype_variant()?),
+            ValueKind::Int => Value::Int(variant.newtype_variant()?),
+            ValueKind::Float => Value::Float(variant.newtype_variant()?),
+            ValueKind::Bool => Value::Bool(variant.newtype_variant()?),
+            ValueKind::Char => Value::Char(variant.newtype_variant()?),
+            ValueKind::List => Value::List(
+                variant
+                    .newtype_variant::<Vec<Tagged<'c>>>()?
+                    .into_iter()
+                    .map(|tagged| tagged.0)
+                    .collect(),
+            ),
+        }))
+    }
+}
+
+impl<T: ListValue + Serialize> Serialize for Node<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut node = serializer.serialize_struct("Node", 3)?;
+        node.serialize_field("value", &self.value())?;
+        node.serialize_field("left", &self.left())?;
+        node.serialize_field("right", &self.right())?;
+        node.end()
+    }
+}
+
+impl<T: ListValue + Serialize> Serialize for BinaryTree<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.root().serialize(serializer)
+    }
+}
+
+/// `NodeData` is the shape of a serialized [`Node`] before it is
+/// linked into a [`BinaryTree`]
+#[derive(Deserialize)]
+#[serde(rename = "Node", bound = "T: Deserialize<'de>")]
+struct NodeData<T> {
+    value: Option<T>,
+    #[serde(default)]
+    left: Option<Box<NodeData<T>>>,
+    #[serde(default)]
+    right: Option<Box<NodeData<T>>>,
+}
+
+impl<T> NodeData<T> {
+    fn value<E: de::Error>(&mut self) -> Result<T, E> {
+        self.value.take().ok_or_else(|| E::missing_field("value"))
+    }
+}
+
+impl<'de, 'c, T: ListValue + Deserialize<'de>> Deserialize<'de> for BinaryTree<'c, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<BinaryTree<'c, T>, D::Error> {
+        let Some(mut root) = Option::<NodeData<T>>::deserialize(deserializer)? else {
+            return Ok(BinaryTree::empty());
+        };
+        let mut tree = BinaryTree::new(root.value()?);
+        let node = tree.root_mut().expect("tree created with a root");
+        link(&tree, node, root)?;
+        Ok(tree)
+    }
+}
+
+/// `link` links the children of `data` under `node`, from the top down
+fn link<'c, T: ListValue, E: de::Error>(
+    tree: &BinaryTree<'c, T>,
+    node: &mut Node<'c, T>,
+    data: NodeData<T>,
+) -> Result<(), E> {
+    if let Some(mut left) = data.left {
+        let child = tree.node(left.value()?);
+        node.set_left(child);
+        link(tree, child, *left)?;
+    }
+    if let Some(mut right) = data.right {
+        let child = tree.node(right.value()?);
+        node.set_right(child);
+        link(tree, child, *right)?;
+    }
+    Ok(())
+}
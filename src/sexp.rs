@@ -0,0 +1,520 @@
+use std::borrow::Cow;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use crate::{symbol, Cell, Value};
+
+/// `read` parses the s-expression in `input` into a [`Cell`], such
+/// that it reads back what [`Cell`]'s `Display` implementation prints.
+///
+/// Lists become the cells of the returned [`Cell`] while a single
+/// atom becomes a [`Cell`] holding only that atom. Both `nil` and
+/// `()` read as [`Cell::nil`] at the top level, whereas lists nested
+/// inside of lists read as [`Value::List`], such that `(nil ())`
+/// holds [`Value::Nil`] followed by an empty [`Value::List`].
+///
+/// A `.` between the last element of a list and one more form reads
+/// as an improper list ending in that form, see [`Cell::end`], such
+/// that `(a . b)` is a dotted pair while, as in Lisp, `(a . (b))`
+/// reads as `(a b)` and `(a . nil)` as `(a)`.
+///
+/// Atoms are read as follows:
+///
+/// - `nil` as [`Value::Nil`]
+/// - strings in double quotes, with the escapes printed by
+///   [`Value`]'s `Debug` implementation, as [`Value::String`]
+/// - integers with the suffixes `u8`, `u64` and `i64` printed by
+///   [`Value`]'s `Debug` implementation as [`Value::Byte`],
+///   [`Value::UInt`] and [`Value::Int`] respectively
+/// - integers without suffix as [`Value::UInt`], or [`Value::Int`]
+///   when negative
+/// - numbers with the suffix `f64`, or with a fraction or exponent,
+///   as [`Value::Float`], including `NaNf64`, `inff64` and `-inff64`
+/// - `true` and `false` as [`Value::Bool`]
+/// - characters in single quotes like `'a'` or `'\n'` as [`Value::Char`]
+/// - any other word, or any name between `|` with `|` and `\`
+///   escaped by `\`, as [`Value::Symbol`]
+///
+/// `'form` reads as `(quote form)` and `;` starts a comment that runs
+/// until the end of the line.
+///
+/// Example
+///
+/// ```
+/// use ds::{sexp, symbol, Value};
+///
+/// let cell = sexp::read("(head \"middle\" 33u8 tail)").unwrap();
+/// assert_eq!(cell.values(), vec![
+///     Value::from(symbol("head")),
+///     Value::from("middle"),
+///     Value::Byte(33),
+///     Value::from(symbol("tail")),
+/// ]);
+/// ```
+pub fn read<'c>(input: &'c str) -> Result<Cell<'c>, ParseError> {
+    let mut reader = Reader::new(input);
+    let cell = reader.read_cell()?;
+    reader.skip_whitespace();
+    if let Some(c) = reader.peek() {
+        return Err(reader.error(ParseErrorKind::TrailingInput(c)));
+    }
+    Ok(cell)
+}
+
+/// `PRETTY_WIDTH` is the number of columns that the alternate
+/// `Display` of [`Cell`] (i.e.: `format!("{:#}", cell)`) fits lists into
+pub const PRETTY_WIDTH: usize = 80;
+
+/// `pretty` prints `cell` as an s-expression like its `Display`
+/// implementation does, except that lists which do not fit in `width`
+/// columns are broken into one element per line, indented past the
+/// opening parenthesis.
+///
+/// Example
+///
+/// ```
+/// use ds::{list, sexp, Cell};
+///
+/// let cell = list!("alpha", "beta", "gamma");
+/// assert_eq!(sexp::pretty(&cell, 80), "(\"alpha\" \"beta\" \"gamma\")");
+/// assert_eq!(sexp::pretty(&cell, 10), "(\"alpha\"\n \"beta\"\n \"gamma\")");
+/// ```
+pub fn pretty(cell: &Cell<'_>, width: usize) -> String {
+    let mut output = String::new();
+    write_pretty(&mut output, cell, 0, width).expect("writing to a String does not fail");
+    output
+}
+
+/// `write` prints the elements of `cell` separated by spaces in
+/// between parentheses, followed by `. end` when `cell` is an
+/// improper list, see [`Cell`]'s `Display` implementation.
+pub(crate) fn write(f: &mut impl std::fmt::Write, cell: &Cell<'_>) -> std::fmt::Result {
+    if cell.is_nil() {
+        return f.write_str("()");
+    }
+    f.write_char('(')?;
+    for (index, value) in cell.iter().enumerate() {
+        if index > 0 {
+            f.write_char(' ')?;
+        }
+        write!(f, "{:?}", value)?;
+    }
+    if let Some(end) = cell.end() {
+        write!(f, " . {:?}", end)?;
+    }
+    f.write_char(')')
+}
+
+/// `write_pretty` prints `cell` at column `indent`, see [`pretty`]
+pub(crate) fn write_pretty(
+    f: &mut impl std::fmt::Write,
+    cell: &Cell<'_>,
+    indent: usize,
+    width: usize,
+) -> std::fmt::Result {
+    let mut line = String::new();
+    write(&mut line, cell)?;
+    if indent + line.chars().count() <= width {
+        return f.write_str(&line);
+    }
+    f.write_char('(')?;
+    for (index, value) in cell.iter().enumerate() {
+        if index > 0 {
+            write!(f, "\n{:indent$}", "", indent = indent + 1)?;
+        }
+        match value {
+            Value::List(list) => write_pretty(f, list, indent + 1, width)?,
+            value => write!(f, "{:?}", value)?,
+        }
+    }
+    if let Some(end) = cell.end() {
+        write!(f, "\n{:indent$}. {:?}", "", end, indent = indent + 1)?;
+    }
+    f.write_char(')')
+}
+
+/// `ParseError` is returned by [`read`] along with the line and column,
+/// both starting at 1, where the input could not be parsed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// the input ended before a form was read
+    UnexpectedEof,
+    /// a `)` without a matching `(`
+    UnexpectedClose,
+    /// a list opened at the position of the error was never closed
+    UnclosedList,
+    /// a string opened at the position of the error was never closed
+    UnterminatedString,
+    /// a character opened at the position of the error was never closed
+    UnterminatedChar,
+    /// a symbol opened by `|` at the position of the error was never closed
+    UnterminatedSymbol,
+    /// an unknown escape sequence inside of a string
+    InvalidEscape(String),
+    /// an integer that does not fit its suffix or is malformed
+    InvalidNumber(String),
+    /// input left after the first form
+    TrailingInput(char),
+    /// a `.` that does not stand between the elements of a list and
+    /// the single form that ends it
+    MisplacedDot,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.kind {
+            ParseErrorKind::UnexpectedEof => write!(f, "unexpected end of input"),
+            ParseErrorKind::UnexpectedClose => write!(f, "unexpected `)`"),
+            ParseErrorKind::UnclosedList => write!(f, "list is never closed"),
+            ParseErrorKind::UnterminatedString => write!(f, "string is never closed"),
+            ParseErrorKind::UnterminatedChar => write!(f, "character is never closed"),
+            ParseErrorKind::UnterminatedSymbol => write!(f, "symbol is never closed"),
+            ParseErrorKind::InvalidEscape(escape) => write!(f, "invalid escape `{}`", escape),
+            ParseErrorKind::InvalidNumber(number) => write!(f, "invalid number `{}`", number),
+            ParseErrorKind::TrailingInput(c) => write!(f, "unexpected `{}` after form", c),
+            ParseErrorKind::MisplacedDot => write!(f, "misplaced `.`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+struct Reader<'c> {
+    input: &'c str,
+    chars: Peekable<CharIndices<'c>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'c> Reader<'c> {
+    fn new(input: &'c str) -> Reader<'c> {
+        Reader {
+            input,
+            chars: input.char_indices().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|(_, c)| *c)
+    }
+
+    fn offset(&mut self) -> usize {
+        self.chars.peek().map(|(offset, _)| *offset).unwrap_or(self.input.len())
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let (_, c) = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        self.error_at(kind, (self.line, self.column))
+    }
+
+    fn error_at(&self, kind: ParseErrorKind, (line, column): (usize, usize)) -> ParseError {
+        ParseError { kind, line, column }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == ';' {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.bump();
+                }
+            } else if c.is_whitespace() {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// `read_cell` reads the top-level form
+    fn read_cell(&mut self) -> Result<Cell<'c>, ParseError> {
+        self.skip_whitespace();
+        let position = (self.line, self.column);
+        match self.peek() {
+            None => Err(self.error(ParseErrorKind::UnexpectedEof)),
+            Some('(') => {
+                self.bump();
+                self.read_list(position)
+            },
+            Some('\'') if !self.is_char() => {
+                self.bump();
+                let value = self.read_value()?;
+                Ok([Value::from(symbol("quote")), value].into_iter().collect())
+            },
+            Some(_) => match self.read_value()? {
+                Value::Nil => Ok(Cell::nil()),
+                value => Ok(Cell::new(value)),
+            },
+        }
+    }
+
+    /// `read_list` reads the elements of a list whose `(` at
+    /// `position` was already consumed
+    fn read_list(&mut self, position: (usize, usize)) -> Result<Cell<'c>, ParseError> {
+        let mut values = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None => return Err(self.error_at(ParseErrorKind::UnclosedList, position)),
+                Some(')') => {
+                    self.bump();
+                    return Ok(values.into_iter().collect());
+                },
+                Some('.') if self.is_dot() => return self.read_end(values, position),
+                Some(_) => values.push(self.read_value()?),
+            }
+        }
+    }
+
+    /// `read_end` reads the `.` ahead and the form after it that ends
+    /// the list of `values` whose `(` at `position` was already consumed
+    fn read_end(
+        &mut self,
+        values: Vec<Value<'c>>,
+        position: (usize, usize),
+    ) -> Result<Cell<'c>, ParseError> {
+        let dot = (self.line, self.column);
+        self.bump();
+        self.skip_whitespace();
+        match self.peek() {
+            None => return Err(self.error_at(ParseErrorKind::UnclosedList, position)),
+            Some(')') => return Err(self.error_at(ParseErrorKind::MisplacedDot, dot)),
+            Some(_) if values.is_empty() => {
+                return Err(self.error_at(ParseErrorKind::MisplacedDot, dot));
+            },
+            Some(_) => {},
+        }
+        let end = self.read_value()?;
+        self.skip_whitespace();
+        match self.peek() {
+            None => Err(self.error_at(ParseErrorKind::UnclosedList, position)),
+            Some(')') => {
+                self.bump();
+                let mut cell = values.into_iter().collect::<Cell>();
+                cell.set_end(end);
+                Ok(cell)
+            },
+            Some(_) => Err(self.error_at(ParseErrorKind::MisplacedDot, dot)),
+        }
+    }
+
+    /// `is_dot` tells whether the `.` ahead stands alone rather than
+    /// starting an atom like `.5`
+    fn is_dot(&mut self) -> bool {
+        self.input[self.offset()..]
+            .chars()
+            .nth(1)
+            .is_none_or(|c| c.is_whitespace() || matches!(c, '(' | ')' | '"' | ';' | '\'' | '|'))
+    }
+
+    /// `read_value` reads a form that is an element of a list
+    fn read_value(&mut self) -> Result<Value<'c>, ParseError> {
+        self.skip_whitespace();
+        let position = (self.line, self.column);
+        match self.peek() {
+            None => Err(self.error(ParseErrorKind::UnexpectedEof)),
+            Some(')') => Err(self.error(ParseErrorKind::UnexpectedClose)),
+            Some('(') => {
+                self.bump();
+                Ok(Value::List(self.read_list(position)?))
+            },
+            Some('\'') if self.is_char() => self.read_char(),
+            Some('\'') => {
+                self.bump();
+                let value = self.read_value()?;
+                Ok(Value::List([Value::from(symbol("quote")), value].into_iter().collect()))
+            },
+            Some('"') => self.read_string(),
+            Some('|') => self.read_symbol(),
+            Some(_) => self.read_atom(),
+        }
+    }
+
+    fn read_string(&mut self) -> Result<Value<'c>, ParseError> {
+        let position = (self.line, self.column);
+        self.bump();
+        let start = self.offset();
+        let mut owned: Option<String> = None;
+        loop {
+            let offset = self.offset();
+            let escape = (self.line, self.column);
+            match self.bump() {
+                None => return Err(self.error_at(ParseErrorKind::UnterminatedString, position)),
+                Some('"') => {
+                    return Ok(Value::String(match owned {
+                        Some(string) => Cow::Owned(string),
+                        None => Cow::Borrowed(&self.input[start..offset]),
+                    }));
+                },
+                Some('\\') => {
+                    let string = owned.get_or_insert_with(|| self.input[start..offset].to_string());
+                    match self.read_escape(escape)? {
+                        Some(c) => string.push(c),
+                        None => {
+                            return Err(self.error_at(ParseErrorKind::UnterminatedString, position));
+                        },
+                    }
+                },
+                Some(c) => {
+                    if let Some(string) = owned.as_mut() {
+                        string.push(c);
+                    }
+                },
+            }
+        }
+    }
+
+    /// `is_char` tells whether the `'` ahead starts a character
+    /// like `'a'` or `'\n'` rather than a quoted form like `'a`
+    fn is_char(&mut self) -> bool {
+        let mut rest = self.input[self.offset()..].chars().skip(1);
+        match rest.next() {
+            Some('\\') => true,
+            Some('\'') | None => false,
+            Some(_) => rest.next() == Some('\''),
+        }
+    }
+
+    fn read_char(&mut self) -> Result<Value<'c>, ParseError> {
+        let position = (self.line, self.column);
+        self.bump();
+        let escape = (self.line, self.column);
+        let c = match self.bump() {
+            Some('\\') => self.read_escape(escape)?,
+            c => c,
+        };
+        match (c, self.bump()) {
+            (Some(c), Some('\'')) => Ok(Value::Char(c)),
+            _ => Err(self.error_at(ParseErrorKind::UnterminatedChar, position)),
+        }
+    }
+
+    /// `read_escape` reads the rest of an escape sequence whose `\`
+    /// at `escape` was already consumed, or `None` at the end of input
+    fn read_escape(&mut self, escape: (usize, usize)) -> Result<Option<char>, ParseError> {
+        Ok(Some(match self.bump() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('0') => '\0',
+            Some('\\') => '\\',
+            Some('"') => '"',
+            Some('\'') => '\'',
+            Some('u') => self.read_unicode_escape(escape)?,
+            Some(c) => {
+                return Err(
+                    self.error_at(ParseErrorKind::InvalidEscape(format!("\\{}", c)), escape)
+                );
+            },
+            None => return Ok(None),
+        }))
+    }
+
+    /// `read_unicode_escape` reads the `{XXXX}` part of a `\u{XXXX}` escape
+    fn read_unicode_escape(&mut self, escape: (usize, usize)) -> Result<char, ParseError> {
+        let mut digits = String::new();
+        if self.peek() == Some('{') {
+            self.bump();
+            while let Some(c) = self.peek().filter(|c| c.is_ascii_hexdigit()) {
+                digits.push(c);
+                self.bump();
+            }
+            if self.peek() == Some('}') {
+                self.bump();
+                if let Some(c) = u32::from_str_radix(&digits, 16).ok().and_then(char::from_u32) {
+                    return Ok(c);
+                }
+            }
+        }
+        Err(self.error_at(ParseErrorKind::InvalidEscape(format!("\\u{}", digits)), escape))
+    }
+
+    fn read_atom(&mut self) -> Result<Value<'c>, ParseError> {
+        let position = (self.line, self.column);
+        let start = self.offset();
+        while self
+            .peek()
+            .is_some_and(|c| !c.is_whitespace() && !matches!(c, '(' | ')' | '"' | ';' | '\'' | '|'))
+        {
+            self.bump();
+        }
+        let atom = &self.input[start..self.offset()];
+        if atom == "nil" {
+            Ok(Value::Nil)
+        } else if atom == "true" || atom == "false" {
+            Ok(Value::Bool(atom == "true"))
+        } else if atom == "." {
+            Err(self.error_at(ParseErrorKind::MisplacedDot, position))
+        } else if is_number(atom) {
+            read_number(atom).ok_or_else(|| {
+                self.error_at(ParseErrorKind::InvalidNumber(atom.to_string()), position)
+            })
+        } else {
+            Ok(Value::Symbol(symbol(atom)))
+        }
+    }
+
+    /// `read_symbol` reads a symbol whose name is between `|`
+    fn read_symbol(&mut self) -> Result<Value<'c>, ParseError> {
+        let position = (self.line, self.column);
+        self.bump();
+        let mut name = String::new();
+        loop {
+            match self.bump() {
+                None => return Err(self.error_at(ParseErrorKind::UnterminatedSymbol, position)),
+                Some('|') => return Ok(Value::Symbol(symbol(&name))),
+                Some('\\') => match self.bump() {
+                    Some(c) => name.push(c),
+                    None => return Err(self.error_at(ParseErrorKind::UnterminatedSymbol, position)),
+                },
+                Some(c) => name.push(c),
+            }
+        }
+    }
+}
+
+/// `is_number` tells whether `atom` reads as a number, see [`read`]
+pub(crate) fn is_number(atom: &str) -> bool {
+    atom.trim_start_matches('-').starts_with(|c: char| c.is_ascii_digit())
+        || matches!(atom, "NaNf64" | "inff64" | "-inff64")
+}
+
+/// `read_number` parses an integer atom with an optional `u8`, `u64`
+/// or `i64` suffix, or a floating point atom with an optional `f64`
+/// suffix
+fn read_number<'c>(atom: &str) -> Option<Value<'c>> {
+    if let Some(digits) = atom.strip_suffix("f64") {
+        digits.parse::<f64>().ok().map(Value::Float)
+    } else if atom.contains(['.', 'e', 'E']) {
+        atom.parse::<f64>().ok().map(Value::Float)
+    } else if let Some(digits) = atom.strip_suffix("u8") {
+        digits.parse::<u8>().ok().map(Value::Byte)
+    } else if let Some(digits) = atom.strip_suffix("u64") {
+        digits.parse::<u64>().ok().map(Value::UInt)
+    } else if let Some(digits) = atom.strip_suffix("i64") {
+        digits.parse::<i64>().ok().map(Value::Int)
+    } else if atom.starts_with('-') {
+        atom.parse::<i64>().ok().map(Value::Int)
+    } else {
+        atom.parse::<u64>().ok().map(Value::UInt)
+    }
+}
@@ -0,0 +1,123 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+/// `Symbol` is an interned name such that two symbols with the same
+/// name are the same `Symbol` and compare equal in constant time.
+///
+/// Symbols are created with [`symbol`] and live until the process
+/// exits, as the names in the global symbol table are never freed.
+///
+/// Symbols are ordered by name rather than by the order in which
+/// they were interned.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol(u32);
+
+/// `Table` is the global symbol table mapping names to the position
+/// of the name in `names`
+#[derive(Default)]
+struct Table {
+    ids: HashMap<&'static str, u32>,
+    names: Vec<&'static str>,
+}
+
+fn table() -> &'static Mutex<Table> {
+    static TABLE: OnceLock<Mutex<Table>> = OnceLock::new();
+    TABLE.get_or_init(Default::default)
+}
+
+/// `symbol` interns `name` in the global symbol table and returns
+/// its [`Symbol`]
+///
+/// Example
+///
+/// ```
+/// use ds::{symbol, Value};
+///
+/// assert_eq!(symbol("foo"), symbol("foo"));
+/// assert_eq!(Value::from(symbol("foo")).to_string(), "foo");
+/// ```
+pub fn symbol(name: &str) -> Symbol {
+    let mut table = table().lock().unwrap_or_else(|error| error.into_inner());
+    if let Some(id) = table.ids.get(name) {
+        return Symbol(*id);
+    }
+    let id = u32::try_from(table.names.len()).expect("symbol table is full");
+    let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+    table.ids.insert(name, id);
+    table.names.push(name);
+    Symbol(id)
+}
+
+impl Symbol {
+    /// `name` returns the name this symbol was interned with
+    pub fn name(&self) -> &'static str {
+        let table = table().lock().unwrap_or_else(|error| error.into_inner());
+        table.names[self.0 as usize]
+    }
+
+    /// `id` returns the position of this symbol in the global symbol
+    /// table, which depends on the order in which symbols are interned
+    pub fn id(&self) -> u32 {
+        self.0
+    }
+
+    /// `is_plain` tells whether the name of this symbol reads back as
+    /// this symbol without the `|` delimiters, see [`crate::sexp::read`]
+    pub(crate) fn is_plain(&self) -> bool {
+        let name = self.name();
+        !name.is_empty()
+            && !name.contains(|c: char| {
+                c.is_whitespace() || matches!(c, '(' | ')' | '"' | ';' | '\'' | '|')
+            })
+            && !matches!(name, "nil" | "true" | "false" | ".")
+            && !crate::sexp::is_number(name)
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(name: &str) -> Symbol {
+        symbol(name)
+    }
+}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Symbol) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Symbol) -> Ordering {
+        if self == other {
+            Ordering::Equal
+        } else {
+            self.name().cmp(other.name())
+        }
+    }
+}
+
+impl std::fmt::Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Prints the symbol as [`crate::sexp::read`] reads it, i.e.: its
+/// name or, when the name would read as something else, the name
+/// between `|` with `|` and `\` escaped
+impl std::fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_plain() {
+            return f.write_str(self.name());
+        }
+        f.write_str("|")?;
+        for c in self.name().chars() {
+            if matches!(c, '|' | '\\') {
+                f.write_str("\\")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        f.write_str("|")
+    }
+}
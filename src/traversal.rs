@@ -0,0 +1,246 @@
+use std::collections::VecDeque;
+
+use crate::{cast_node_mut, cast_node_ref, internal, ListValue, Node, Value};
+
+/// `children` returns the raw pointers to the left and right children
+/// of `node`, reading the links without touching any [`RefCounter`](crate::RefCounter)
+fn children<'c, T: ListValue>(node: *mut Node<'c, T>) -> [*mut Node<'c, T>; 2] {
+    let node = cast_node_ref!(node);
+    [&node.left, &node.right].map(|child| {
+        if child.is_null() {
+            internal::null::node()
+        } else {
+            child.cast_mut()
+        }
+    })
+}
+
+/// `InOrder` iterates over the nodes of a subtree in the order
+/// left, node, right, see [`Node::iter_inorder`]
+pub struct InOrder<'c, T = Value<'c>> {
+    stack: Vec<*mut Node<'c, T>>,
+    next: *mut Node<'c, T>,
+}
+
+impl<'c, T: ListValue> InOrder<'c, T> {
+    pub(crate) fn new(root: *mut Node<'c, T>) -> InOrder<'c, T> {
+        InOrder {
+            stack: Vec::new(),
+            next: root,
+        }
+    }
+
+    /// `values` turns this iterator into one over the items of the nodes
+    pub fn values(self) -> Values<InOrder<'c, T>> {
+        Values(self)
+    }
+
+    fn next_ptr(&mut self) -> Option<*mut Node<'c, T>> {
+        while !self.next.is_null() {
+            self.stack.push(self.next);
+            self.next = children(self.next)[0];
+        }
+        let node = self.stack.pop()?;
+        self.next = children(node)[1];
+        Some(node)
+    }
+}
+
+impl<'c, T: ListValue> Iterator for InOrder<'c, T> {
+    type Item = &'c Node<'c, T>;
+
+    fn next(&mut self) -> Option<&'c Node<'c, T>> {
+        self.next_ptr().map(|node| cast_node_ref!(node))
+    }
+}
+
+/// `PreOrder` iterates over the nodes of a subtree in the order
+/// node, left, right, see [`Node::iter_preorder`]
+pub struct PreOrder<'c, T = Value<'c>> {
+    stack: Vec<*mut Node<'c, T>>,
+}
+
+impl<'c, T: ListValue> PreOrder<'c, T> {
+    pub(crate) fn new(root: *mut Node<'c, T>) -> PreOrder<'c, T> {
+        PreOrder {
+            stack: if root.is_null() { Vec::new() } else { vec![root] },
+        }
+    }
+
+    /// `values` turns this iterator into one over the items of the nodes
+    pub fn values(self) -> Values<PreOrder<'c, T>> {
+        Values(self)
+    }
+
+    fn next_ptr(&mut self) -> Option<*mut Node<'c, T>> {
+        let node = self.stack.pop()?;
+        let [left, right] = children(node);
+        self.stack.extend([right, left].into_iter().filter(|child| !child.is_null()));
+        Some(node)
+    }
+}
+
+impl<'c, T: ListValue> Iterator for PreOrder<'c, T> {
+    type Item = &'c Node<'c, T>;
+
+    fn next(&mut self) -> Option<&'c Node<'c, T>> {
+        self.next_ptr().map(|node| cast_node_ref!(node))
+    }
+}
+
+/// `PostOrder` iterates over the nodes of a subtree in the order
+/// left, right, node, see [`Node::iter_postorder`]
+pub struct PostOrder<'c, T = Value<'c>> {
+    stack: Vec<(*mut Node<'c, T>, bool)>,
+}
+
+impl<'c, T: ListValue> PostOrder<'c, T> {
+    pub(crate) fn new(root: *mut Node<'c, T>) -> PostOrder<'c, T> {
+        PostOrder {
+            stack: if root.is_null() { Vec::new() } else { vec![(root, false)] },
+        }
+    }
+
+    /// `values` turns this iterator into one over the items of the nodes
+    pub fn values(self) -> Values<PostOrder<'c, T>> {
+        Values(self)
+    }
+
+    fn next_ptr(&mut self) -> Option<*mut Node<'c, T>> {
+        loop {
+            let (node, visited) = self.stack.pop()?;
+            if visited {
+                return Some(node);
+            }
+            let [left, right] = children(node);
+            self.stack.push((node, true));
+            self.stack.extend(
+                [right, left]
+                    .into_iter()
+                    .filter(|child| !child.is_null())
+                    .map(|child| (child, false)),
+            );
+        }
+    }
+}
+
+impl<'c, T: ListValue> Iterator for PostOrder<'c, T> {
+    type Item = &'c Node<'c, T>;
+
+    fn next(&mut self) -> Option<&'c Node<'c, T>> {
+        self.next_ptr().map(|node| cast_node_ref!(node))
+    }
+}
+
+/// `LevelOrder` iterates over the nodes of a subtree breadth-first,
+/// from left to right within each level, see [`Node::iter_level_order`]
+pub struct LevelOrder<'c, T = Value<'c>> {
+    queue: VecDeque<*mut Node<'c, T>>,
+}
+
+impl<'c, T: ListValue> LevelOrder<'c, T> {
+    pub(crate) fn new(root: *mut Node<'c, T>) -> LevelOrder<'c, T> {
+        LevelOrder {
+            queue: if root.is_null() { VecDeque::new() } else { VecDeque::from([root]) },
+        }
+    }
+
+    /// `values` turns this iterator into one over the items of the nodes
+    pub fn values(self) -> Values<LevelOrder<'c, T>> {
+        Values(self)
+    }
+
+    fn next_ptr(&mut self) -> Option<*mut Node<'c, T>> {
+        let node = self.queue.pop_front()?;
+        self.queue.extend(children(node).into_iter().filter(|child| !child.is_null()));
+        Some(node)
+    }
+}
+
+impl<'c, T: ListValue> Iterator for LevelOrder<'c, T> {
+    type Item = &'c Node<'c, T>;
+
+    fn next(&mut self) -> Option<&'c Node<'c, T>> {
+        self.next_ptr().map(|node| cast_node_ref!(node))
+    }
+}
+
+/// `Values` adapts a traversal into an iterator over the items of
+/// the nodes it visits, skipping nodes without an item
+pub struct Values<I>(I);
+
+impl<'c, T: ListValue + 'c, I: Iterator<Item = &'c Node<'c, T>>> Iterator for Values<I> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.by_ref().find_map(Node::value)
+    }
+}
+
+/// `InOrderMut` is the mutable counterpart of [`InOrder`], see [`Node::iter_inorder_mut`]
+pub struct InOrderMut<'c, T = Value<'c>>(InOrder<'c, T>);
+
+impl<'c, T: ListValue> InOrderMut<'c, T> {
+    pub(crate) fn new(root: *mut Node<'c, T>) -> InOrderMut<'c, T> {
+        InOrderMut(InOrder::new(root))
+    }
+}
+
+impl<'c, T: ListValue> Iterator for InOrderMut<'c, T> {
+    type Item = &'c mut Node<'c, T>;
+
+    fn next(&mut self) -> Option<&'c mut Node<'c, T>> {
+        self.0.next_ptr().map(|node| cast_node_mut!(node, noincr))
+    }
+}
+
+/// `PreOrderMut` is the mutable counterpart of [`PreOrder`], see [`Node::iter_preorder_mut`]
+pub struct PreOrderMut<'c, T = Value<'c>>(PreOrder<'c, T>);
+
+impl<'c, T: ListValue> PreOrderMut<'c, T> {
+    pub(crate) fn new(root: *mut Node<'c, T>) -> PreOrderMut<'c, T> {
+        PreOrderMut(PreOrder::new(root))
+    }
+}
+
+impl<'c, T: ListValue> Iterator for PreOrderMut<'c, T> {
+    type Item = &'c mut Node<'c, T>;
+
+    fn next(&mut self) -> Option<&'c mut Node<'c, T>> {
+        self.0.next_ptr().map(|node| cast_node_mut!(node, noincr))
+    }
+}
+
+/// `PostOrderMut` is the mutable counterpart of [`PostOrder`], see [`Node::iter_postorder_mut`]
+pub struct PostOrderMut<'c, T = Value<'c>>(PostOrder<'c, T>);
+
+impl<'c, T: ListValue> PostOrderMut<'c, T> {
+    pub(crate) fn new(root: *mut Node<'c, T>) -> PostOrderMut<'c, T> {
+        PostOrderMut(PostOrder::new(root))
+    }
+}
+
+impl<'c, T: ListValue> Iterator for PostOrderMut<'c, T> {
+    type Item = &'c mut Node<'c, T>;
+
+    fn next(&mut self) -> Option<&'c mut Node<'c, T>> {
+        self.0.next_ptr().map(|node| cast_node_mut!(node, noincr))
+    }
+}
+
+/// `LevelOrderMut` is the mutable counterpart of [`LevelOrder`], see [`Node::iter_level_order_mut`]
+pub struct LevelOrderMut<'c, T = Value<'c>>(LevelOrder<'c, T>);
+
+impl<'c, T: ListValue> LevelOrderMut<'c, T> {
+    pub(crate) fn new(root: *mut Node<'c, T>) -> LevelOrderMut<'c, T> {
+        LevelOrderMut(LevelOrder::new(root))
+    }
+}
+
+impl<'c, T: ListValue> Iterator for LevelOrderMut<'c, T> {
+    type Item = &'c mut Node<'c, T>;
+
+    fn next(&mut self) -> Option<&'c mut Node<'c, T>> {
+        self.0.next_ptr().map(|node| cast_node_mut!(node, noincr))
+    }
+}
@@ -4,8 +4,9 @@ use std::fmt::{Debug, Display, Formatter, Pointer};
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
 
-use crate::{color, decr_ref_nonzero, internal, step, warn, RefCounter};
+use crate::{color, decr_ref_nonzero, internal, step, warn, RefCounter, WeakPointer};
 
 /// [`UniquePointer`] is an experimental data structure that makes
 /// extensive use of unsafe rust to provide a shared pointer
@@ -51,8 +52,10 @@ use crate::{color, decr_ref_nonzero, internal, step, warn, RefCounter};
 /// references in strategic occasions such as incrementing its
 /// reference count within its [`Clone`] implementation.
 ///
-/// UniquePointer only supports [`Sized`] types, that is,
-/// Zero-Sized-Types (ZSTs) are not supported.
+/// [`UniquePointer`] supports unsized types such as [`str`], slices
+/// and trait objects through [`from_box`], [`from_slice`] and
+/// [`from_str`], whereas Zero-Sized-Types (ZSTs) are written to a
+/// dangling but aligned pointer without allocating memory.
 ///
 /// Example
 ///
@@ -74,52 +77,60 @@ use crate::{color, decr_ref_nonzero, internal, step, warn, RefCounter};
 /// assert_eq!(value.as_ref(), Some(&"string"));
 /// ```
 ///
-/// > # NOTE: **[`UniquePointer`] IS NOT THREAD SAFE** (yet)
+/// The value is dropped exactly once, when the last of its references
+/// is released via [`dealloc`]. Dropping a [`UniquePointer`] without
+/// calling [`dealloc`] leaks its value.
 ///
-pub struct UniquePointer<T> {
+/// [`downgrade`] creates a [`WeakPointer`] which observes the value
+/// without counting as a reference to it.
+///
+/// The accessors [`try_inner_ref`], [`try_inner_mut`], [`try_read`]
+/// and [`try_write`] return a [`PointerError`] where their panicking
+/// counterparts [`inner_ref`], [`inner_mut`], [`read`] and [`write`]
+/// would panic, rather than dereferencing a NULL, unwritten or freed
+/// pointer.
+///
+/// > # NOTE: **[`UniquePointer`] IS NOT THREAD SAFE**, see
+/// > [`AtomicUniquePointer`](crate::AtomicUniquePointer)
+///
+pub struct UniquePointer<T: ?Sized> {
     mut_addr: usize,
-    mut_ptr: *mut T,
+    mut_ptr: Option<NonNull<T>>,
     orig_addr: usize,
     refs: RefCounter,
     alloc: bool,
     is_copy: bool,
+    borrowed: bool,
     written: bool,
 }
 
-impl<'c, T: Sized + 'c> UniquePointer<T> {
-    /// `null` creates a NULL [`UniquePointer`] ready to be written via [`write`].
+impl<'c, T: ?Sized + 'c> UniquePointer<T> {
+    /// `null` creates a NULL [`UniquePointer`] ready to be written via
+    /// [`write`], which allocates nothing until then.
     pub fn null() -> UniquePointer<T> {
         UniquePointer {
             mut_addr: 0,
-            mut_ptr: std::ptr::null_mut::<T>(),
+            mut_ptr: None,
             orig_addr: 0,
-            refs: RefCounter::new(),
+            refs: RefCounter::null(),
             written: false,
             alloc: false,
             is_copy: false,
+            borrowed: false,
         }
     }
 
-    /// `from_ref` creates a new [`UniquePointer`] by effectively
-    /// reading the value from [`reference`] after extending its
-    /// lifetime from `'r` to `'c`
-    ///
-    pub fn from_ref<'r>(reference: &'r T) -> UniquePointer<T> {
-        let reference = unsafe { std::mem::transmute::<&'r T, &'c T>(reference) };
-        let mut up = UniquePointer::<T>::null();
-        up.write_ref(reference);
-        up
-    }
-
-    /// `from_ref_mut` creates a new [`UniquePointer`] by effectively
-    /// reading the value from [`mutable_reference`] after extending its
-    /// lifetime from `'r` to `'c`
-    ///
-    pub fn from_ref_mut<'r>(mutable_reference: &'r mut T) -> UniquePointer<T> {
-        let mut mutable_reference =
-            unsafe { std::mem::transmute::<&'r mut T, &'c mut T>(mutable_reference) };
+    /// `from_box` creates a new [`UniquePointer`] which takes over the
+    /// value and the memory of `data`, as is the case for unsized
+    /// values such as `Box<str>`, `Box<[T]>` or `Box<dyn Trait>`
+    pub fn from_box(data: Box<T>) -> UniquePointer<T> {
         let mut up = UniquePointer::<T>::null();
-        up.write_ref_mut(mutable_reference);
+        let mut_ptr = NonNull::from(Box::leak(data));
+        up.set_mut_ptr(Some(mut_ptr));
+        up.orig_addr = mut_ptr.as_ptr().addr();
+        up.refs = RefCounter::new();
+        up.alloc = true;
+        up.written = true;
         up
     }
 
@@ -153,7 +164,7 @@ impl<'c, T: Sized + 'c> UniquePointer<T> {
     pub unsafe fn propagate(&self) -> UniquePointer<T> {
         self.incr_ref();
         let mut back_node = UniquePointer::<T>::null();
-        back_node.set_mut_ptr(self.mut_ptr, false);
+        back_node.set_mut_ptr(self.mut_ptr);
         back_node.refs = self.refs.clone();
         back_node.orig_addr = self.orig_addr;
         back_node.alloc = self.alloc;
@@ -161,25 +172,6 @@ impl<'c, T: Sized + 'c> UniquePointer<T> {
         back_node
     }
 
-    pub fn copy_from_ref(data: &T, refs: usize, orig_addr: usize) -> UniquePointer<T> {
-        let ptr = (data as *const T).cast_mut();
-        UniquePointer::copy_from_mut_ptr(ptr, refs, orig_addr)
-    }
-
-    pub fn copy_from_mut_ptr(ptr: *mut T, refs: usize, orig_addr: usize) -> UniquePointer<T> {
-        let addr = UniquePointer::provenance_of_mut_ptr(ptr);
-        let refs = RefCounter::from(refs);
-        UniquePointer {
-            mut_addr: addr,
-            mut_ptr: ptr,
-            orig_addr: orig_addr,
-            refs: refs,
-            written: true,
-            alloc: true,
-            is_copy: true,
-        }
-    }
-
     /// `addr` returns the value containing both the provenance and
     /// memory address of a pointer
     pub fn addr(&self) -> usize {
@@ -191,14 +183,20 @@ impl<'c, T: Sized + 'c> UniquePointer<T> {
         self.orig_addr
     }
 
-    /// `refs` returns the reference count of a `UniquePointer`
+    /// `refs` returns the reference count of a `UniquePointer`, where
+    /// a NULL pointer, which allocates no [`RefCounter`] until written,
+    /// counts as the only reference to itself
     pub fn refs(&self) -> usize {
-        *self.refs
+        if self.refs.is_null() {
+            1
+        } else {
+            *self.refs
+        }
     }
 
     /// `is_null` returns true if the [`UniquePointer`] is NULL.
     pub fn is_null(&self) -> bool {
-        let mut_is_null = self.mut_ptr.is_null();
+        let mut_is_null = self.mut_ptr.is_none();
         if mut_is_null {
             assert!(self.mut_addr == 0);
         } else {
@@ -225,19 +223,28 @@ impl<'c, T: Sized + 'c> UniquePointer<T> {
     }
 
     /// `can_dealloc` returns true if the [`UniquePointer`] is not NULL
-    /// and is not flagged as a copy, meaning it can be deallocated
-    /// without concern for double-free.
+    /// and points to memory it allocated, as opposed to the memory
+    /// borrowed by [`copy_from_ref`] and [`copy_from_mut_ptr`], meaning
+    /// that the last of its references can deallocate it.
     pub fn can_dealloc(&self) -> bool {
-        self.alloc && self.is_not_copy() && self.is_not_null()
+        self.alloc && !self.borrowed && self.is_not_null()
     }
 
     /// `is_allocated` returns true if the [`UniquePointer`] has been
     /// allocated and therefore is no longer a NULL pointer.
     pub fn is_allocated(&self) -> bool {
-        let is_allocated = self.is_not_null() && self.alloc;
+        let is_allocated = self.is_not_null() && self.alloc && !self.is_freed();
         is_allocated
     }
 
+    /// `is_freed` returns true if the value of this [`UniquePointer`]
+    /// has been freed by another of its references via a "hard"
+    /// [`dealloc`], after which this [`UniquePointer`] only needs to
+    /// be deallocated in turn.
+    pub fn is_freed(&self) -> bool {
+        self.is_not_null() && self.refs.is_released()
+    }
+
     /// `is_written` returns true if the [`UniquePointer`] has been written to
     pub fn is_written(&self) -> bool {
         let is_written = self.is_allocated() && self.written;
@@ -252,112 +259,74 @@ impl<'c, T: Sized + 'c> UniquePointer<T> {
         self.is_copy
     }
 
-    /// `alloc` allocates memory in a null `UniquePointer`
-    pub fn alloc(&mut self) {
-        if self.is_allocated() {
-            // warn!("{:#?} is already allocated, force-deallocating now", &self);
-            self.dealloc(false);
-            return;
-        }
-
-        let layout = Layout::new::<T>();
-        let mut_ptr = unsafe {
-            let ptr = std::alloc::alloc_zeroed(layout);
-            if ptr.is_null() {
-                std::alloc::handle_alloc_error(layout);
-            }
-            ptr as *mut T
-        };
-        self.set_mut_ptr(mut_ptr, false);
-        self.alloc = true;
-        // step!("self.incr_ref()");
-        // self.incr_ref();
-    }
-
-    /// `write` allocates memory and writes the given value into the
-    /// newly allocated area.
-    pub fn write(&mut self, data: T) {
-        let orig_addr = UniquePointer::<T>::raw_addr_of_ref(&data);
-        self.alloc();
-
-        unsafe {
-            self.mut_ptr.write(data);
-        }
-
-        self.written = true;
-        self.orig_addr = orig_addr;
-    }
-
-    /// `read` increments reference count and returns the internal value `T`.
-    pub fn read(&self) -> T {
-        if !self.is_written() {
-            panic!("{:#?} not written", self);
-        }
-        let mut ptr = self.cast_const();
-        unsafe { ptr.read() }
-    }
-
-    /// `write_ref_mut` takes a mutable reference to a value and
-    /// writes to a `UniquePointer`
-    pub fn write_ref_mut(&mut self, data: &mut T) {
-        self.write(unsafe {
-            let ptr = data as *mut T;
-            ptr.read()
-        });
-    }
-
-    /// `write_ref` takes a read-only reference to a value and
-    /// writes to a `UniquePointer`
-    pub fn write_ref(&mut self, data: &T) {
-        self.write(unsafe {
-            let ptr = data as *const T;
-            ptr.read()
-        });
-    }
-
     /// `cast_mut` is a compatibility API to a raw mut pointer's [`pointer::cast_mut`].
     pub fn cast_mut(&self) -> *mut T {
-        if self.is_null() {
-            // return std::ptr::null_mut::<T>()
-            panic!("{:#?}", self);
-        } else {
-            self.mut_ptr
+        match self.mut_ptr {
+            Some(mut_ptr) => mut_ptr.as_ptr(),
+            None => panic!("{:#?}", self),
         }
     }
 
     /// `cast_mut` is a compatibility API to a raw const pointer's [`pointer::cast_const`].
     pub fn cast_const(&self) -> *const T {
-        if self.is_null() {
-            // return std::ptr::null::<T>()
-            panic!("{:#?}", self);
-        } else {
-            self.mut_ptr.cast_const()
+        self.cast_mut().cast_const()
+    }
+
+    /// `check` returns the [`PointerError`] preventing the value of
+    /// this [`UniquePointer`] from being dereferenced, if any
+    fn check(&self) -> Result<NonNull<T>, PointerError> {
+        match self.mut_ptr {
+            None => Err(PointerError::Null),
+            Some(_) if self.is_freed() => Err(PointerError::Freed),
+            Some(_) if !self.is_written() => Err(PointerError::NotWritten),
+            Some(mut_ptr) => Ok(mut_ptr),
         }
     }
 
     /// `peek_ref` obtains a read-only reference to the value inside
-    /// [`UniquePointer`] but does not increment references
+    /// [`UniquePointer`] but does not increment references, see
+    /// [`inner_ref`]
     pub fn peek_ref(&self) -> &'c T {
-        unsafe { std::mem::transmute::<&T, &'c T>(&*self.cast_const()) }
+        self.inner_ref()
     }
 
     /// `peek_mut` obtains a mutable reference to the value inside
-    /// [`UniquePointer`] but does not increment references
+    /// [`UniquePointer`] but does not increment references, see
+    /// [`inner_mut`]
     pub fn peek_mut(&mut self) -> &'c mut T {
-        unsafe { std::mem::transmute::<&mut T, &'c mut T>(&mut *self.mut_ptr) }
+        self.inner_mut()
+    }
+
+    /// `try_inner_ref` obtains a read-only reference to the value
+    /// inside [`UniquePointer`], or the [`PointerError`] preventing it
+    pub fn try_inner_ref<'a>(&self) -> Result<&'a T, PointerError> {
+        let mut_ptr = self.check()?;
+        Ok(unsafe { std::mem::transmute::<&T, &'a T>(mut_ptr.as_ref()) })
+    }
+
+    /// `try_inner_mut` obtains a mutable reference to the value
+    /// inside [`UniquePointer`], or the [`PointerError`] preventing it
+    pub fn try_inner_mut<'a>(&mut self) -> Result<&'a mut T, PointerError> {
+        let mut mut_ptr = self.check()?;
+        Ok(unsafe { std::mem::transmute::<&mut T, &'a mut T>(mut_ptr.as_mut()) })
     }
 
     /// `inner_ref` obtains a read-only reference to the value inside
-    /// [`UniquePointer`] and increments reference
+    /// [`UniquePointer`], panicking where [`try_inner_ref`] fails
     pub fn inner_ref<'a>(&self) -> &'a T {
-        unsafe { std::mem::transmute::<&'c T, &'a T>(self.peek_ref()) }
+        match self.try_inner_ref() {
+            Ok(data) => data,
+            Err(error) => panic!("{:#?} {}", self, error),
+        }
     }
 
     /// `inner_mut` obtains a mutable reference to the value inside
-    /// [`UniquePointer`] and increments reference
+    /// [`UniquePointer`], panicking where [`try_inner_mut`] fails
     pub fn inner_mut<'a>(&mut self) -> &'a mut T {
-        // step!("{:#?}", self);
-        unsafe { std::mem::transmute::<&'c mut T, &'a mut T>(self.peek_mut()) }
+        match self.try_inner_mut() {
+            Ok(data) => data,
+            Err(error) => panic!("{:#?} {}", self, error),
+        }
     }
 
     /// `as_ref` is a compatibility layer to the [`AsRef`] implementation in raw pointers
@@ -387,51 +356,46 @@ impl<'c, T: Sized + 'c> UniquePointer<T> {
     /// During "soft" deallocation (`soft=true`) calls to `dealloc`
     /// only really deallocate memory when the reference gets down to
     /// zero, until then each `dealloc(true)` call simply decrements
-    /// the reference count.
+    /// the reference count and the [`UniquePointer`] in question
+    /// becomes NULL.
     ///
     /// Conversely during "hard" deallocation (`soft=false`) the
     /// UniquePointer in question gets immediately deallocated,
     /// possibly incurring a double-free or causing Undefined
-    /// Behavior.
+    /// Behavior, unless it is a copy sharing the value with other
+    /// references, in which case it is soft-deallocated.
+    ///
+    /// The value is dropped along with the memory which holds it,
+    /// see [`free`]. Deallocating a [`UniquePointer`] whose value was
+    /// already freed by another reference, see [`is_freed`], only
+    /// releases its reference, whereas deallocating a [`UniquePointer`]
+    /// which borrows its value, see [`copy_from_ref`], leaves both the
+    /// value and its reference count to their owner.
     pub fn dealloc(&mut self, soft: bool) {
         if self.is_null() {
             return;
         }
-        if !soft && self.refs > 0 {
-            self.decr_ref();
+        if self.borrowed {
+            self.forget();
+        } else if self.is_freed() {
+            self.refs.dealloc_weak();
+            self.set_mut_ptr(None);
+            self.alloc = false;
+            self.written = false;
+        } else if self.refs > 1 && (soft || self.is_copy) {
+            self.release();
         } else {
             self.free();
         }
     }
 
-    /// `set_mut_ptr` sets the internal raw pointer of a `UniquePointer`.
-    ///
-    /// Prior to setting the new pointer, it checks whether the
-    /// internal pointer is non-null and matches its provenance
-    /// address, such that "copies" do not incur a double-free.
-    ///
-    /// When [`ptr`] is a NULL pointer and the internal pointer of
-    /// [`UniquePointer`] in question is NOT NULL, then it is
-    /// deallocated prior to setting it to NULL.
-    fn set_mut_ptr(&mut self, ptr: *mut T, dealloc: bool) {
-        if ptr.is_null() {
-            if dealloc && self.can_dealloc() {
-                // unsafe {
-                //     self.mut_ptr.drop_in_place();
-                // }
-                self.alloc = false;
-                self.written = false;
-                // warn!("deallocating {:#?}", self);
-                let layout = Layout::new::<T>();
-                let mut_ptr = self.mut_ptr;
-                unsafe {
-                    std::alloc::dealloc(self.mut_ptr as *mut u8, layout);
-                };
-            }
-
-            self.set_mut_addr(0);
-        } else {
-            self.set_mut_addr(UniquePointer::<T>::provenance_of_mut_ptr(ptr));
+    /// `set_mut_ptr` sets the internal pointer of a `UniquePointer`
+    /// along with its provenance address, see [`free`] for the
+    /// deallocation of the memory it pointed to.
+    fn set_mut_ptr(&mut self, ptr: Option<NonNull<T>>) {
+        match ptr {
+            Some(ptr) => self.set_mut_addr(ptr.as_ptr().cast::<u8>().expose_provenance()),
+            None => self.set_mut_addr(0),
         }
         self.mut_ptr = ptr;
     }
@@ -446,13 +410,258 @@ impl<'c, T: Sized + 'c> UniquePointer<T> {
     ///
     /// See [`dealloc`] for more information regarding the difference
     /// between "soft" and "hard" deallocation.
+    ///
+    /// The value is dropped before its memory is deallocated unless
+    /// it was never written. When dropping the value panics, the
+    /// memory is deallocated nonetheless and the panic resumes once
+    /// the [`UniquePointer`] is NULL, such that the value is never
+    /// dropped twice.
+    ///
+    /// The memory of Zero-Sized-Types is never allocated and so only
+    /// their value is dropped.
+    ///
+    /// The references left when a shared value is freed keep the
+    /// [`RefCounter`] around as weak references, such that they
+    /// observe the value as freed, see [`is_freed`].
     fn free(&mut self) {
-        if !self.is_null() {
-            self.set_mut_ptr(std::ptr::null_mut::<T>(), false);
-            self.refs.dealloc();
+        let mut dropped = Ok(());
+        if let Some(mut_ptr) = self.mut_ptr {
+            let can_dealloc = self.can_dealloc();
+            if can_dealloc {
+                // the value may never have been written, hence the
+                // layout comes from the pointer rather than a reference
+                let layout = unsafe { Layout::for_value_raw(mut_ptr.as_ptr()) };
+                if self.written {
+                    dropped = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+                        mut_ptr.as_ptr().drop_in_place();
+                    }));
+                }
+                if layout.size() > 0 {
+                    unsafe { std::alloc::dealloc(mut_ptr.as_ptr().cast::<u8>(), layout) };
+                }
+            }
+            self.set_mut_ptr(None);
+            if can_dealloc {
+                self.refs.incr_weak_by(self.refs.read().saturating_sub(1));
+                self.refs.dealloc();
+            }
         }
         self.alloc = false;
         self.written = false;
+        if let Err(panic) = dropped {
+            std::panic::resume_unwind(panic);
+        }
+    }
+
+    /// `release` is internally used by [`dealloc`] to decrement the
+    /// reference count of a value shared with other references, which
+    /// keep the value, and turn this [`UniquePointer`] into a NULL
+    /// pointer.
+    fn release(&mut self) {
+        self.decr_ref();
+        self.forget();
+    }
+
+    /// `forget` turns this [`UniquePointer`] into a NULL pointer
+    /// without touching its value nor its reference count
+    fn forget(&mut self) {
+        self.set_mut_ptr(None);
+        self.refs = RefCounter::null();
+        self.alloc = false;
+        self.written = false;
+    }
+}
+
+impl<T: Sized> UniquePointer<T> {
+    pub fn copy_from_ref(data: &T, refs: usize, orig_addr: usize) -> UniquePointer<T> {
+        let ptr = (data as *const T).cast_mut();
+        UniquePointer::copy_from_mut_ptr(ptr, refs, orig_addr)
+    }
+
+    pub fn copy_from_mut_ptr(ptr: *mut T, refs: usize, orig_addr: usize) -> UniquePointer<T> {
+        let addr = UniquePointer::provenance_of_mut_ptr(ptr);
+        let refs = RefCounter::from(refs);
+        UniquePointer {
+            mut_addr: addr,
+            mut_ptr: NonNull::new(ptr),
+            orig_addr: orig_addr,
+            refs: refs,
+            written: true,
+            alloc: true,
+            is_copy: true,
+            borrowed: true,
+        }
+    }
+
+    /// `borrowed` creates a [`UniquePointer`] to the value at `ptr`
+    /// owned elsewhere which shares `refs` with its owner without
+    /// incrementing it, such that [`dealloc`] neither frees the value
+    /// nor its reference count, see [`Node::ptr`](crate::Node::ptr)
+    pub(crate) fn borrowed(ptr: *mut T, refs: &RefCounter) -> UniquePointer<T> {
+        let mut up = UniquePointer::<T>::copy();
+        up.set_mut_ptr(NonNull::new(ptr));
+        up.refs = refs.clone();
+        up.alloc = true;
+        up.written = true;
+        up.borrowed = true;
+        up
+    }
+
+    /// `shared` creates a copy of a [`UniquePointer`] to the value at
+    /// `ptr` which shares and increments `refs`, as [`Clone`] does,
+    /// see [`WeakPointer::upgrade`]
+    pub(crate) fn shared(ptr: *mut T, refs: &RefCounter) -> UniquePointer<T> {
+        let mut up = UniquePointer::<T>::copy();
+        up.set_mut_ptr(NonNull::new(ptr));
+        up.refs = refs.clone();
+        up.alloc = true;
+        up.written = true;
+        up.incr_ref();
+        up
+    }
+
+    /// `downgrade` creates a [`WeakPointer`] to the value of this
+    /// [`UniquePointer`] which does not count as a reference to it,
+    /// such that [`WeakPointer::upgrade`] returns [`None`] once the
+    /// value has been deallocated.
+    pub fn downgrade(&self) -> WeakPointer<T> {
+        if self.is_written() {
+            WeakPointer::new(self.cast_mut(), &self.refs)
+        } else {
+            WeakPointer::null()
+        }
+    }
+
+    /// `alloc` allocates memory in a null `UniquePointer`, keeping
+    /// the memory of an already allocated one.
+    ///
+    /// Zero-Sized-Types are given a dangling but aligned pointer
+    /// rather than allocated memory.
+    pub fn alloc(&mut self) {
+        if self.is_allocated() {
+            return;
+        }
+
+        let layout = Layout::new::<T>();
+        let mut_ptr = if layout.size() == 0 {
+            NonNull::dangling()
+        } else {
+            let ptr = unsafe { std::alloc::alloc_zeroed(layout) };
+            match NonNull::new(ptr as *mut T) {
+                Some(ptr) => ptr,
+                None => std::alloc::handle_alloc_error(layout),
+            }
+        };
+        self.set_mut_ptr(Some(mut_ptr));
+        self.refs = RefCounter::new();
+        self.alloc = true;
+        // step!("self.incr_ref()");
+        // self.incr_ref();
+    }
+
+    /// `try_write` allocates memory and writes the given value into
+    /// the newly allocated area.
+    ///
+    /// Writing to a [`UniquePointer`] already written to replaces the
+    /// value shared with its clones and drops the previous value.
+    ///
+    /// Writing fails with [`PointerError::Freed`] once the value was
+    /// freed by another reference and with [`PointerError::Aliased`]
+    /// when the value is owned by the reference it was copied from,
+    /// see [`copy_from_ref`] and [`copy_from_mut_ptr`].
+    pub fn try_write(&mut self, data: T) -> Result<(), PointerError> {
+        if self.is_freed() {
+            return Err(PointerError::Freed);
+        }
+        if self.borrowed {
+            return Err(PointerError::Aliased);
+        }
+        let orig_addr = UniquePointer::<T>::raw_addr_of_ref(&data);
+        if self.is_written() {
+            let previous = unsafe { self.cast_mut().replace(data) };
+            self.orig_addr = orig_addr;
+            drop(previous);
+            return Ok(());
+        }
+        self.alloc();
+
+        unsafe {
+            self.cast_mut().write(data);
+        }
+
+        self.written = true;
+        self.orig_addr = orig_addr;
+        Ok(())
+    }
+
+    /// `write` is [`try_write`] panicking where it fails
+    pub fn write(&mut self, data: T) {
+        if let Err(error) = self.try_write(data) {
+            panic!("{:#?} {}", self, error);
+        }
+    }
+}
+
+impl<T: Clone> UniquePointer<T> {
+    /// `from_ref` creates a new [`UniquePointer`] holding a clone of
+    /// the value behind [`reference`]
+    ///
+    pub fn from_ref(reference: &T) -> UniquePointer<T> {
+        let mut up = UniquePointer::<T>::null();
+        up.write_ref(reference);
+        up
+    }
+
+    /// `from_ref_mut` creates a new [`UniquePointer`] holding a clone
+    /// of the value behind [`mutable_reference`]
+    ///
+    pub fn from_ref_mut(mutable_reference: &mut T) -> UniquePointer<T> {
+        let mut up = UniquePointer::<T>::null();
+        up.write_ref_mut(mutable_reference);
+        up
+    }
+
+    /// `try_read` returns a clone of the internal value `T`, such
+    /// that the value is still dropped exactly once, see [`free`], or
+    /// the [`PointerError`] preventing it
+    pub fn try_read(&self) -> Result<T, PointerError> {
+        self.try_inner_ref().cloned()
+    }
+
+    /// `read` is [`try_read`] panicking where it fails
+    pub fn read(&self) -> T {
+        match self.try_read() {
+            Ok(data) => data,
+            Err(error) => panic!("{:#?} {}", self, error),
+        }
+    }
+
+    /// `write_ref_mut` takes a mutable reference to a value and
+    /// writes a clone of it to a `UniquePointer`
+    pub fn write_ref_mut(&mut self, data: &mut T) {
+        self.write(data.clone());
+    }
+
+    /// `write_ref` takes a read-only reference to a value and
+    /// writes a clone of it to a `UniquePointer`
+    pub fn write_ref(&mut self, data: &T) {
+        self.write(data.clone());
+    }
+}
+
+impl<T: Clone> UniquePointer<[T]> {
+    /// `from_slice` creates a new [`UniquePointer`] holding clones of
+    /// the items of `slice`
+    pub fn from_slice(slice: &[T]) -> UniquePointer<[T]> {
+        UniquePointer::from_box(Box::from(slice))
+    }
+}
+
+impl UniquePointer<str> {
+    /// `from_str` creates a new [`UniquePointer`] holding a copy of `string`
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(string: &str) -> UniquePointer<str> {
+        UniquePointer::from_box(Box::from(string))
     }
 }
 
@@ -508,7 +717,7 @@ impl<T: Sized> UniquePointer<T> {
     }
 }
 
-impl<'c, T: Sized + 'c> UniquePointer<T> {
+impl<'c, T: ?Sized + 'c> UniquePointer<T> {
     /// `meta_mut` is an unsafe method that turns a "self reference"
     /// into a mutable "self reference"
     unsafe fn meta_mut(&'c self) -> &'c mut UniquePointer<T> {
@@ -530,7 +739,9 @@ impl<'c, T: Sized + 'c> UniquePointer<T> {
     }
 }
 #[allow(invalid_reference_casting)]
-impl<T: Sized> UniquePointer<T> {
+impl<T: ?Sized> UniquePointer<T> {
+    /// `incr_ref` increments the reference count, or the weak count
+    /// once the value has been freed, see [`free`]
     fn incr_ref(&self) {
         if self.is_null() {
             // panic!("null {:#?}", self);
@@ -539,7 +750,11 @@ impl<T: Sized> UniquePointer<T> {
         unsafe {
             let ptr = self.meta_mut_ptr();
             let mut up = &mut *ptr;
-            up.refs.incr();
+            if up.is_freed() {
+                up.refs.incr_weak();
+            } else {
+                up.refs.incr();
+            }
         }
     }
 
@@ -555,7 +770,7 @@ impl<T: Sized> UniquePointer<T> {
         }
     }
 }
-impl<T: Sized> Deref for UniquePointer<T> {
+impl<T: ?Sized> Deref for UniquePointer<T> {
     type Target = T;
 
     fn deref(&self) -> &T {
@@ -563,13 +778,13 @@ impl<T: Sized> Deref for UniquePointer<T> {
     }
 }
 
-impl<T: Sized> DerefMut for UniquePointer<T> {
+impl<T: ?Sized> DerefMut for UniquePointer<T> {
     fn deref_mut(&mut self) -> &mut T {
         self.inner_mut()
     }
 }
 
-impl<T: Sized> Drop for UniquePointer<T> {
+impl<T: ?Sized> Drop for UniquePointer<T> {
     fn drop(&mut self) {
         // if self.is_written() {
         //     self.dealloc(true);
@@ -577,16 +792,21 @@ impl<T: Sized> Drop for UniquePointer<T> {
     }
 }
 
-impl<T: Sized> From<&T> for UniquePointer<T> {
+impl<T: Clone> From<&T> for UniquePointer<T> {
     fn from(data: &T) -> UniquePointer<T> {
         UniquePointer::<T>::from_ref(data)
     }
 }
-impl<T: Sized> From<&mut T> for UniquePointer<T> {
+impl<T: Clone> From<&mut T> for UniquePointer<T> {
     fn from(data: &mut T) -> UniquePointer<T> {
         UniquePointer::<T>::from_ref_mut(data)
     }
 }
+impl<T: ?Sized> From<Box<T>> for UniquePointer<T> {
+    fn from(data: Box<T>) -> UniquePointer<T> {
+        UniquePointer::<T>::from_box(data)
+    }
+}
 impl<T: Sized> From<T> for UniquePointer<T> {
     fn from(data: T) -> UniquePointer<T> {
         let mut up = UniquePointer::<T>::null();
@@ -597,11 +817,11 @@ impl<T: Sized> From<T> for UniquePointer<T> {
 /// The [`Clone`] implementation of [`UniquePointer`] is special because
 /// it flags cloned values as clones such that a double-free doesn not
 /// occur.
-impl<T: Sized> Clone for UniquePointer<T> {
+impl<T: ?Sized> Clone for UniquePointer<T> {
     fn clone(&self) -> UniquePointer<T> {
         self.incr_ref();
         let mut clone = UniquePointer::<T>::copy();
-        clone.set_mut_ptr(self.mut_ptr, false);
+        clone.set_mut_ptr(self.mut_ptr);
         clone.refs = self.refs.clone();
         clone.alloc = self.alloc;
         clone.written = self.written;
@@ -609,13 +829,13 @@ impl<T: Sized> Clone for UniquePointer<T> {
     }
 }
 
-impl<T: Sized> Pointer for UniquePointer<T> {
+impl<T: ?Sized> Pointer for UniquePointer<T> {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "{:016x}", self.addr())
     }
 }
 
-impl<T: Sized> Debug for UniquePointer<T> {
+impl<T: ?Sized> Debug for UniquePointer<T> {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(
             f,
@@ -628,6 +848,7 @@ impl<T: Sized> Debug for UniquePointer<T> {
                     format!("[alloc={}]", self.alloc),
                     format!("[written={}]", self.written),
                     format!("[is_copy={}]", self.is_copy),
+                    format!("[borrowed={}]", self.borrowed),
                     format!("[orig_addr={:016x}]", self.orig_addr),
                 ]
                 .join("")
@@ -687,3 +908,32 @@ impl<T: Deref<Target: Hash> + Hash> Hash for UniquePointer<T> {
         T::hash(self, state);
     }
 }
+
+/// `PointerError` is returned by the checked accessors of
+/// [`UniquePointer`] such as [`UniquePointer::try_inner_ref`] where
+/// dereferencing the pointer would be undefined behavior
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointerError {
+    /// a NULL pointer, see [`UniquePointer::null`]
+    Null,
+    /// a pointer allocated but never written to
+    NotWritten,
+    /// a pointer whose value was freed by another of its references
+    Freed,
+    /// a write through a pointer to a value owned by the reference it
+    /// was copied from, see [`UniquePointer::copy_from_ref`]
+    Aliased,
+}
+
+impl std::fmt::Display for PointerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PointerError::Null => write!(f, "is NULL"),
+            PointerError::NotWritten => write!(f, "not written"),
+            PointerError::Freed => write!(f, "freed by another reference"),
+            PointerError::Aliased => write!(f, "aliases a value it does not own"),
+        }
+    }
+}
+
+impl std::error::Error for PointerError {}
@@ -1,23 +1,60 @@
 use std::borrow::{Borrow, Cow, ToOwned};
+use std::cmp::Ordering;
 use std::ops::Deref;
 use std::rc::Rc;
 
-use crate::{color, step};
+use crate::{color, step, Cell, Symbol};
 
-#[derive(Clone, PartialOrd, Ord, Default, PartialEq, Eq)]
+/// Values of different variants are ordered by the order in which
+/// the variants are declared, i.e.: `nil` sorts before any string,
+/// strings before bytes and so on, while values of the same variant
+/// are ordered by their contents.
+///
+/// [`Value::Float`] is compared with [`f64::total_cmp`] so that
+/// [`Value`] has a total order, in which `-NaN < -inf < -0.0 < 0.0 <
+/// inf < NaN`, and equality, in which `NaN` equals itself while
+/// `-0.0` and `0.0` differ.
+///
+/// Numbers support the arithmetic operators, which promote their
+/// operands along `Byte → UInt → Int → Float`, and can be compared
+/// by value across variants with [`Value::numeric_cmp`].
+#[derive(Clone, Default)]
 pub enum Value<'c> {
     #[default]
     Nil,
     String(Cow<'c, str>),
+    /// `Symbol` is an interned name, see [`crate::symbol`]
+    Symbol(Symbol),
     Byte(u8),
     UInt(u64),
     Int(i64),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+    /// `List` is a nested list, which makes a [`Cell`] a tree of values
+    List(Cell<'c>),
 }
 impl<'c> Value<'_> {
     pub fn nil() -> Value<'c> {
         Value::Nil
     }
 
+    /// `kind` returns the [`ValueKind`] of the variant of this value
+    pub fn kind(&self) -> ValueKind {
+        match self {
+            Value::Nil => ValueKind::Nil,
+            Value::String(_) => ValueKind::String,
+            Value::Symbol(_) => ValueKind::Symbol,
+            Value::Byte(_) => ValueKind::Byte,
+            Value::UInt(_) => ValueKind::UInt,
+            Value::Int(_) => ValueKind::Int,
+            Value::Float(_) => ValueKind::Float,
+            Value::Bool(_) => ValueKind::Bool,
+            Value::Char(_) => ValueKind::Char,
+            Value::List(_) => ValueKind::List,
+        }
+    }
+
     pub fn is_nil(&self) -> bool {
         if *self == Value::Nil {
             true
@@ -27,8 +64,70 @@ impl<'c> Value<'_> {
     }
 }
 
-impl<'c> Drop for Value<'c> {
-    fn drop(&mut self) {}
+/// `OwnedValue` is a [`Value`] that borrows nothing, see [`Value::into_owned`]
+pub type OwnedValue = Value<'static>;
+
+impl<'c> Value<'c> {
+    /// `into_owned` copies the strings this value borrows, including
+    /// those of nested lists, such that the value outlives them.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use ds::{OwnedValue, Value};
+    ///
+    /// fn word(text: &str) -> OwnedValue {
+    ///     Value::from(text.split(' ').next().unwrap()).into_owned()
+    /// }
+    /// assert_eq!(word("hello world"), Value::from("hello"));
+    /// ```
+    pub fn into_owned(self) -> Value<'static> {
+        match self {
+            Value::Nil => Value::Nil,
+            Value::String(h) => Value::String(Cow::Owned(h.into_owned())),
+            Value::Symbol(h) => Value::Symbol(h),
+            Value::Byte(h) => Value::Byte(h),
+            Value::UInt(h) => Value::UInt(h),
+            Value::Int(h) => Value::Int(h),
+            Value::Float(h) => Value::Float(h),
+            Value::Bool(h) => Value::Bool(h),
+            Value::Char(h) => Value::Char(h),
+            Value::List(h) => Value::List(h.into_owned()),
+        }
+    }
+}
+
+/// `ValueKind` names the variants of [`Value`] without their
+/// contents, ordered as [`Value`] orders values of different variants
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValueKind {
+    Nil,
+    String,
+    Symbol,
+    Byte,
+    UInt,
+    Int,
+    Float,
+    Bool,
+    Char,
+    List,
+}
+
+impl std::fmt::Display for ValueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            ValueKind::Nil => "nil",
+            ValueKind::String => "string",
+            ValueKind::Symbol => "symbol",
+            ValueKind::Byte => "u8",
+            ValueKind::UInt => "u64",
+            ValueKind::Int => "i64",
+            ValueKind::Float => "f64",
+            ValueKind::Bool => "bool",
+            ValueKind::Char => "char",
+            ValueKind::List => "list",
+        })
+    }
 }
 
 impl std::fmt::Display for Value<'_> {
@@ -39,9 +138,14 @@ impl std::fmt::Display for Value<'_> {
             match self {
                 Value::Nil => "nil".to_string(),
                 Value::String(h) => format!("{}", h),
+                Value::Symbol(h) => format!("{}", h),
                 Value::Byte(h) => format!("{}", h),
                 Value::UInt(h) => format!("{}", h),
                 Value::Int(h) => format!("{}", h),
+                Value::Float(h) => format!("{}", h),
+                Value::Bool(h) => format!("{}", h),
+                Value::Char(h) => format!("{}", h),
+                Value::List(h) => format!("{}", h),
             }
         )
     }
@@ -54,9 +158,14 @@ impl std::fmt::Debug for Value<'_> {
             match self {
                 Value::Nil => "nil".to_string(),
                 Value::String(h) => format!("{:#?}", h),
+                Value::Symbol(h) => format!("{:?}", h),
                 Value::Byte(h) => format!("{}u8", h),
                 Value::UInt(h) => format!("{}u64", h),
                 Value::Int(h) => format!("{}i64", h),
+                Value::Float(h) => format!("{:?}f64", h),
+                Value::Bool(h) => format!("{}", h),
+                Value::Char(h) => format!("{:?}", h),
+                Value::List(h) => format!("{}", h),
             }
         )
     }
@@ -77,6 +186,26 @@ impl<'c> From<i64> for Value<'c> {
         Value::Int(value)
     }
 }
+impl<'c> From<Symbol> for Value<'c> {
+    fn from(value: Symbol) -> Value<'c> {
+        Value::Symbol(value)
+    }
+}
+impl<'c> From<f64> for Value<'c> {
+    fn from(value: f64) -> Value<'c> {
+        Value::Float(value)
+    }
+}
+impl<'c> From<bool> for Value<'c> {
+    fn from(value: bool) -> Value<'c> {
+        Value::Bool(value)
+    }
+}
+impl<'c> From<char> for Value<'c> {
+    fn from(value: char) -> Value<'c> {
+        Value::Char(value)
+    }
+}
 impl<'c> From<&'c str> for Value<'c> {
     fn from(value: &'c str) -> Value<'c> {
         Value::String(Cow::from(value))
@@ -106,6 +235,78 @@ impl<'c> From<Option<String>> for Value<'c> {
     }
 }
 
+impl<'c> From<Cell<'c>> for Value<'c> {
+    fn from(value: Cell<'c>) -> Value<'c> {
+        Value::List(value)
+    }
+}
+
+/// Values compare equal regardless of the lifetime of the strings
+/// they borrow, given that a [`Value::List`] makes [`Value`]
+/// invariant over it.
+impl<'a, 'b> PartialEq<Value<'b>> for Value<'a> {
+    fn eq(&self, other: &Value<'b>) -> bool {
+        match (self, other) {
+            (Value::Nil, Value::Nil) => true,
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::Symbol(a), Value::Symbol(b)) => a == b,
+            (Value::Byte(a), Value::Byte(b)) => a == b,
+            (Value::UInt(a), Value::UInt(b)) => a == b,
+            (Value::Int(a), Value::Int(b)) => a == b,
+            (Value::Float(a), Value::Float(b)) => a.total_cmp(b).is_eq(),
+            (Value::Bool(a), Value::Bool(b)) => a == b,
+            (Value::Char(a), Value::Char(b)) => a == b,
+            (Value::List(a), Value::List(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Value<'_> {}
+
+/// Hashes the kind of the value along with its contents, where
+/// [`Value::Float`] hashes its bits in agreement with its equality.
+impl std::hash::Hash for Value<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.kind().hash(state);
+        match self {
+            Value::Nil => {},
+            Value::String(h) => h.hash(state),
+            Value::Symbol(h) => h.hash(state),
+            Value::Byte(h) => h.hash(state),
+            Value::UInt(h) => h.hash(state),
+            Value::Int(h) => h.hash(state),
+            Value::Float(h) => h.to_bits().hash(state),
+            Value::Bool(h) => h.hash(state),
+            Value::Char(h) => h.hash(state),
+            Value::List(h) => h.hash(state),
+        }
+    }
+}
+
+impl<'c> PartialOrd<Value<'c>> for Value<'c> {
+    fn partial_cmp(&self, other: &Value<'c>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'c> Ord for Value<'c> {
+    fn cmp(&self, other: &Value<'c>) -> Ordering {
+        match (self, other) {
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::Symbol(a), Value::Symbol(b)) => a.cmp(b),
+            (Value::Byte(a), Value::Byte(b)) => a.cmp(b),
+            (Value::UInt(a), Value::UInt(b)) => a.cmp(b),
+            (Value::Int(a), Value::Int(b)) => a.cmp(b),
+            (Value::Float(a), Value::Float(b)) => a.total_cmp(b),
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::Char(a), Value::Char(b)) => a.cmp(b),
+            (Value::List(a), Value::List(b)) => a.cmp(b),
+            _ => self.kind().cmp(&other.kind()),
+        }
+    }
+}
+
 impl<'c> PartialEq<&Value<'c>> for Value<'c> {
     fn eq(&self, other: &&Value<'c>) -> bool {
         let other = unsafe { &**other };
@@ -119,3 +320,185 @@ impl<'c> PartialEq<&mut Value<'c>> for Value<'c> {
         self == other
     }
 }
+
+/// `ValueError` is returned by operations on [`Value`] that do not
+/// apply to the values given, such as the arithmetic operators and
+/// the conversions out of [`Value`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValueError {
+    /// an operand that is not a number, printed by [`Value`]'s `Debug`
+    NotNumeric(String),
+    /// a result that does not fit [`Value::Int`] nor [`Value::UInt`]
+    Overflow,
+    /// an integer divided by zero
+    DivisionByZero,
+    /// a value of kind `actual` where a value of kind `expected` was
+    /// expected
+    Mismatch {
+        expected: ValueKind,
+        actual: ValueKind,
+    },
+    /// an integer of kind `actual` whose value, printed by [`Value`]'s
+    /// `Debug`, does not fit the integer type of kind `expected`
+    OutOfRange {
+        expected: ValueKind,
+        actual: ValueKind,
+        value: String,
+    },
+    /// an owned [`Value::String`] where a string borrowed for the
+    /// lifetime of the [`Value`] was expected
+    NotBorrowed,
+}
+
+impl std::fmt::Display for ValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ValueError::NotNumeric(value) => write!(f, "{} is not a number", value),
+            ValueError::Overflow => write!(f, "arithmetic overflow"),
+            ValueError::DivisionByZero => write!(f, "division by zero"),
+            ValueError::Mismatch { expected, actual } => {
+                write!(f, "expected {}, found {}", expected, actual)
+            },
+            ValueError::OutOfRange {
+                expected,
+                actual,
+                value,
+            } => write!(f, "{} {} does not fit {}", actual, value, expected),
+            ValueError::NotBorrowed => write!(f, "expected a borrowed string, found an owned one"),
+        }
+    }
+}
+
+impl std::error::Error for ValueError {}
+
+impl ValueError {
+    fn mismatch(expected: ValueKind, value: &Value<'_>) -> ValueError {
+        ValueError::Mismatch {
+            expected,
+            actual: value.kind(),
+        }
+    }
+}
+
+/// `integer` returns the integer held by `value` as `T`, accepting
+/// any integer variant whose value fits `T`, whose kind is `expected`
+fn integer<T: TryFrom<i128>>(value: &Value<'_>, expected: ValueKind) -> Result<T, ValueError> {
+    let n = match value {
+        Value::Byte(n) => i128::from(*n),
+        Value::UInt(n) => i128::from(*n),
+        Value::Int(n) => i128::from(*n),
+        value => return Err(ValueError::mismatch(expected, value)),
+    };
+    T::try_from(n).map_err(|_| ValueError::OutOfRange {
+        expected,
+        actual: value.kind(),
+        value: format!("{:?}", value),
+    })
+}
+
+/// Implements `TryFrom<Value>` and `TryFrom<&Value>` for `$type` and
+/// `Option<$type>`, where `nil` converts to `None`, from a function
+/// converting a `&Value`
+macro_rules! try_from_value {
+    ($type:ty, |$value:ident| $convert:expr) => {
+        impl<'a, 'c> TryFrom<&'a Value<'c>> for $type {
+            type Error = ValueError;
+
+            fn try_from($value: &'a Value<'c>) -> Result<$type, ValueError> {
+                $convert
+            }
+        }
+        impl<'c> TryFrom<Value<'c>> for $type {
+            type Error = ValueError;
+
+            fn try_from(value: Value<'c>) -> Result<$type, ValueError> {
+                <$type>::try_from(&value)
+            }
+        }
+        impl<'a, 'c> TryFrom<&'a Value<'c>> for Option<$type> {
+            type Error = ValueError;
+
+            fn try_from(value: &'a Value<'c>) -> Result<Option<$type>, ValueError> {
+                match value {
+                    Value::Nil => Ok(None),
+                    value => <$type>::try_from(value).map(Some),
+                }
+            }
+        }
+        impl<'c> TryFrom<Value<'c>> for Option<$type> {
+            type Error = ValueError;
+
+            fn try_from(value: Value<'c>) -> Result<Option<$type>, ValueError> {
+                Option::<$type>::try_from(&value)
+            }
+        }
+    };
+}
+
+try_from_value!(u8, |value| integer(value, ValueKind::Byte));
+try_from_value!(u64, |value| integer(value, ValueKind::UInt));
+try_from_value!(i64, |value| integer(value, ValueKind::Int));
+try_from_value!(f64, |value| match value {
+    Value::Float(n) => Ok(*n),
+    value => Err(ValueError::mismatch(ValueKind::Float, value)),
+});
+try_from_value!(bool, |value| match value {
+    Value::Bool(b) => Ok(*b),
+    value => Err(ValueError::mismatch(ValueKind::Bool, value)),
+});
+try_from_value!(char, |value| match value {
+    Value::Char(c) => Ok(*c),
+    value => Err(ValueError::mismatch(ValueKind::Char, value)),
+});
+try_from_value!(String, |value| match value {
+    Value::String(string) => Ok(string.to_string()),
+    value => Err(ValueError::mismatch(ValueKind::String, value)),
+});
+
+impl<'a, 'c> TryFrom<&'a Value<'c>> for &'a str {
+    type Error = ValueError;
+
+    fn try_from(value: &'a Value<'c>) -> Result<&'a str, ValueError> {
+        match value {
+            Value::String(string) => Ok(string.as_ref()),
+            value => Err(ValueError::mismatch(ValueKind::String, value)),
+        }
+    }
+}
+
+/// Borrows the string of a [`Value::String`] for the lifetime `'c`,
+/// which only a string borrowed in the first place, such as one read
+/// by [`crate::sexp::read`] without escapes, can be.
+impl<'c> TryFrom<Value<'c>> for &'c str {
+    type Error = ValueError;
+
+    fn try_from(value: Value<'c>) -> Result<&'c str, ValueError> {
+        match value {
+            Value::String(Cow::Borrowed(string)) => Ok(string),
+            Value::String(Cow::Owned(_)) => Err(ValueError::NotBorrowed),
+            value => Err(ValueError::mismatch(ValueKind::String, &value)),
+        }
+    }
+}
+
+impl<'a, 'c> TryFrom<&'a Value<'c>> for Option<&'a str> {
+    type Error = ValueError;
+
+    fn try_from(value: &'a Value<'c>) -> Result<Option<&'a str>, ValueError> {
+        match value {
+            Value::Nil => Ok(None),
+            value => <&'a str>::try_from(value).map(Some),
+        }
+    }
+}
+
+impl<'c> TryFrom<Value<'c>> for Option<&'c str> {
+    type Error = ValueError;
+
+    fn try_from(value: Value<'c>) -> Result<Option<&'c str>, ValueError> {
+        match value {
+            Value::Nil => Ok(None),
+            value => <&'c str>::try_from(value).map(Some),
+        }
+    }
+}
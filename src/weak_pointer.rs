@@ -0,0 +1,217 @@
+use std::fmt::{Debug, Formatter, Pointer};
+
+use crate::{RefCounter, UniquePointer};
+
+/// [`WeakPointer`] is a non-owning reference to the value of a
+/// [`UniquePointer`], created via [`UniquePointer::downgrade`].
+///
+/// A [`WeakPointer`] counts in the "weak" count of the
+/// [`RefCounter`] shared by the [`UniquePointer`] it was created
+/// from rather than in its "strong" count, such that it neither keeps
+/// the value alive nor needs to be released before the value is
+/// deallocated. Once the value has been deallocated, [`upgrade`]
+/// returns [`None`].
+///
+/// As [`UniquePointer`], [`WeakPointer`] is managed manually: its
+/// weak reference is released via [`dealloc`] rather than when
+/// dropped.
+///
+/// Example
+///
+/// ```
+/// use ds::UniquePointer;
+///
+/// let mut value = UniquePointer::from("string");
+/// let mut weak = value.downgrade();
+///
+/// let mut upgraded = weak.upgrade().unwrap();
+/// assert_eq!(upgraded.read(), "string");
+/// upgraded.dealloc(true);
+///
+/// value.dealloc(true);
+/// assert_eq!(weak.upgrade().is_none(), true);
+///
+/// weak.dealloc();
+/// assert_eq!(weak.is_null(), true);
+/// ```
+///
+/// > # NOTE: **[`WeakPointer`] IS NOT THREAD SAFE**
+///
+pub struct WeakPointer<T> {
+    mut_ptr: *mut T,
+    refs: RefCounter,
+}
+
+impl<'c, T: Sized + 'c> WeakPointer<T> {
+    /// `null` creates a NULL [`WeakPointer`] whose [`upgrade`] always
+    /// returns [`None`].
+    pub fn null() -> WeakPointer<T> {
+        WeakPointer {
+            mut_ptr: std::ptr::null_mut::<T>(),
+            refs: RefCounter::null(),
+        }
+    }
+
+    /// `new` creates a [`WeakPointer`] to the value at `ptr` whose
+    /// strong count is kept by `refs`, incrementing its weak count
+    pub(crate) fn new(ptr: *mut T, refs: &RefCounter) -> WeakPointer<T> {
+        if ptr.is_null() || refs.is_null() {
+            return WeakPointer::null();
+        }
+        let mut refs = refs.clone();
+        refs.incr_weak();
+        WeakPointer { mut_ptr: ptr, refs }
+    }
+
+    /// `addr` returns the memory address of the value
+    pub fn addr(&self) -> usize {
+        self.mut_ptr.addr()
+    }
+
+    /// `is_null` returns true if the [`WeakPointer`] is NULL.
+    pub fn is_null(&self) -> bool {
+        self.mut_ptr.is_null()
+    }
+
+    pub fn is_not_null(&self) -> bool {
+        !self.is_null()
+    }
+
+    /// `is_dangling` returns true if the [`WeakPointer`] is not NULL
+    /// but its value has been deallocated
+    pub fn is_dangling(&self) -> bool {
+        self.is_not_null() && self.refs.is_released()
+    }
+
+    /// `strong_count` returns the reference count of the
+    /// [`UniquePointer`] this [`WeakPointer`] was created from, which
+    /// is zero once its value has been deallocated
+    pub fn strong_count(&self) -> usize {
+        self.refs.strong()
+    }
+
+    /// `weak_count` returns the number of [`WeakPointer`]s sharing
+    /// the reference count of this [`WeakPointer`]
+    pub fn weak_count(&self) -> usize {
+        self.refs.weak()
+    }
+
+    /// `upgrade` returns a [`UniquePointer`] sharing the value, which
+    /// increments its reference count as [`Clone`] does, or [`None`]
+    /// when the value has been deallocated
+    pub fn upgrade(&self) -> Option<UniquePointer<T>> {
+        if self.is_null() || self.is_dangling() {
+            None
+        } else {
+            Some(UniquePointer::shared(self.mut_ptr, &self.refs))
+        }
+    }
+
+    /// `cast_mut` is a compatibility API to a raw mut pointer's [`pointer::cast_mut`].
+    pub fn cast_mut(&self) -> *mut T {
+        if self.is_null() {
+            panic!("{:#?}", self);
+        } else {
+            self.mut_ptr
+        }
+    }
+
+    /// `cast_const` is a compatibility API to a raw const pointer's [`pointer::cast_const`].
+    pub fn cast_const(&self) -> *const T {
+        self.cast_mut().cast_const()
+    }
+
+    /// `as_ref` obtains a read-only reference to the value without
+    /// incrementing references, or [`None`] when the [`WeakPointer`]
+    /// is NULL or dangling
+    pub fn as_ref(&self) -> Option<&'c T> {
+        if self.is_null() || self.is_dangling() {
+            None
+        } else {
+            Some(unsafe { std::mem::transmute::<&T, &'c T>(&*self.mut_ptr) })
+        }
+    }
+
+    /// `as_mut` obtains a mutable reference to the value without
+    /// incrementing references, or [`None`] when the [`WeakPointer`]
+    /// is NULL or dangling
+    pub fn as_mut(&mut self) -> Option<&'c mut T> {
+        if self.is_null() || self.is_dangling() {
+            None
+        } else {
+            Some(unsafe { std::mem::transmute::<&mut T, &'c mut T>(&mut *self.mut_ptr) })
+        }
+    }
+
+    /// `inner_ref` is [`as_ref`] panicking when the [`WeakPointer`]
+    /// is NULL or dangling
+    pub fn inner_ref<'a>(&self) -> &'a T {
+        match self.as_ref() {
+            Some(data) => unsafe { std::mem::transmute::<&'c T, &'a T>(data) },
+            None => panic!("{:#?} not upgradable", self),
+        }
+    }
+
+    /// `inner_mut` is [`as_mut`] panicking when the [`WeakPointer`]
+    /// is NULL or dangling
+    pub fn inner_mut<'a>(&mut self) -> &'a mut T {
+        match self.as_mut() {
+            Some(data) => unsafe { std::mem::transmute::<&'c mut T, &'a mut T>(data) },
+            None => panic!("{:#?} not upgradable", self),
+        }
+    }
+
+    /// `dealloc` releases the weak reference of this [`WeakPointer`],
+    /// which becomes NULL, along with the memory of the reference
+    /// count when both its strong and weak counts get down to zero.
+    pub fn dealloc(&mut self) {
+        if self.is_null() {
+            return;
+        }
+        self.refs.dealloc_weak();
+        self.mut_ptr = std::ptr::null_mut::<T>();
+    }
+}
+
+impl<T> Drop for WeakPointer<T> {
+    fn drop(&mut self) {
+        // weak references are released manually, see [`WeakPointer::dealloc`]
+    }
+}
+
+impl<T: Sized> Default for WeakPointer<T> {
+    fn default() -> WeakPointer<T> {
+        WeakPointer::null()
+    }
+}
+
+/// Clones of a [`WeakPointer`] each count as a weak reference.
+impl<T: Sized> Clone for WeakPointer<T> {
+    fn clone(&self) -> WeakPointer<T> {
+        WeakPointer::new(self.mut_ptr, &self.refs)
+    }
+}
+
+impl<T: Sized> Pointer for WeakPointer<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{:016x}", self.addr())
+    }
+}
+
+impl<T> Debug for WeakPointer<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            crate::color::reset(
+                [
+                    crate::color::fg("WeakPointer@", 237),
+                    format!("{:016x}", self.mut_ptr.addr()),
+                    format!("[refs={}]", self.refs.strong()),
+                    format!("[weak={}]", self.refs.weak()),
+                ]
+                .join("")
+            )
+        )
+    }
+}
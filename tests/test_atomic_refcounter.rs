@@ -0,0 +1,74 @@
+#![allow(unused)]
+use std::sync::Arc;
+use std::thread;
+
+use ds::*;
+use k9::assert_equal;
+
+#[test]
+fn test_atomic_refcounter_incr_decr_read() {
+    let mut counter = AtomicRefCounter::new();
+    assert_equal!(counter.read(), 1);
+    assert_equal!(counter.incr(), 2);
+    assert_equal!(counter.incr_by(2), 4);
+    {
+        let clone = counter.clone();
+        clone.incr();
+        assert_equal!(counter.read(), 5);
+        assert_equal!(clone.read(), 5);
+    }
+    assert_equal!(counter.decr(), 4);
+    assert_equal!(counter.decr_by(3), 1);
+    assert_equal!(counter.decr_by(2), 1);
+    assert_equal!(counter.decr(), 0);
+    assert_equal!(counter.decr(), 0);
+    counter.dealloc();
+    assert_equal!(counter.is_null(), true);
+}
+
+#[test]
+fn test_atomic_refcounter_null() {
+    let counter = AtomicRefCounter::null();
+    assert_equal!(counter.read(), 0);
+    assert_equal!(counter.incr(), 0);
+    assert_equal!(counter.decr(), 0);
+}
+
+#[test]
+fn test_atomic_refcounter_reset_and_compare() {
+    let mut counter = AtomicRefCounter::from(3);
+    assert_equal!(counter == 3, true);
+    assert_equal!(counter > 2, true);
+    counter.reset();
+    assert_equal!(counter.read(), 1);
+    counter.dealloc();
+}
+
+#[test]
+fn test_atomic_refcounter_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<AtomicRefCounter>();
+}
+
+#[test]
+fn test_atomic_refcounter_concurrent_incr_decr() {
+    let mut counter = AtomicRefCounter::new();
+    let threads = (0..8)
+        .map(|_| {
+            let counter = counter.clone();
+            thread::spawn(move || {
+                for _ in 0..10_000 {
+                    counter.incr();
+                }
+                for _ in 0..9_999 {
+                    counter.decr();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_equal!(counter.read(), 9);
+    counter.dealloc();
+}
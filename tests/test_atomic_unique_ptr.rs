@@ -0,0 +1,231 @@
+#![allow(unused)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use ds::*;
+use k9::{assert_equal, assert_greater_than};
+
+/// `Tracked` counts how many times it was dropped
+#[derive(Clone, Debug)]
+struct Tracked {
+    value: usize,
+    drops: Arc<AtomicUsize>,
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.drops.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn tracked(value: usize) -> (AtomicUniquePointer<Tracked>, Arc<AtomicUsize>) {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let pointer = AtomicUniquePointer::from(Tracked {
+        value,
+        drops: drops.clone(),
+    });
+    (pointer, drops)
+}
+
+#[test]
+fn test_atomic_unique_pointer_null() {
+    let pointer = AtomicUniquePointer::<Value>::null();
+    assert_equal!(pointer.is_null(), true);
+    assert_equal!(pointer.addr(), 0);
+    assert_equal!(pointer.refs(), 0);
+    assert_equal!(pointer.is_written(), false);
+    assert_equal!(pointer.is_allocated(), false);
+    assert_equal!(pointer.as_ref(), None);
+}
+
+#[test]
+fn test_atomic_unique_pointer_write_read() {
+    let mut pointer = AtomicUniquePointer::<Value>::null();
+    pointer.write(Value::from("string"));
+
+    assert_equal!(pointer.is_null(), false);
+    assert_equal!(pointer.is_allocated(), true);
+    assert_greater_than!(pointer.addr(), 0, "address should not be null");
+    assert_equal!(pointer.is_written(), true);
+    assert_equal!(pointer.refs(), 1);
+    assert_equal!(pointer.inner_ref(), &Value::from("string"));
+    assert_equal!(pointer.read(), Value::from("string"));
+    assert_equal!(pointer.as_ref(), Some(&Value::from("string")));
+    assert_equal!(*pointer, Value::from("string"));
+}
+
+#[test]
+#[should_panic(expected = "not written")]
+fn test_atomic_unique_pointer_read_null() {
+    AtomicUniquePointer::<Value>::null().read();
+}
+
+#[test]
+fn test_atomic_unique_pointer_clone_shares_value() {
+    let pointer = AtomicUniquePointer::from(Value::from("string"));
+    let clone = pointer.clone();
+    assert_equal!(pointer.refs(), 2);
+    assert_equal!(clone.refs(), 2);
+    assert_equal!(clone.addr(), pointer.addr());
+    drop(clone);
+    assert_equal!(pointer.refs(), 1);
+}
+
+#[test]
+fn test_atomic_unique_pointer_write_detaches_clones() {
+    let mut pointer = AtomicUniquePointer::from(Value::from("string"));
+    let clone = pointer.clone();
+    pointer.write(Value::from("updated"));
+
+    assert_equal!(pointer.read(), Value::from("updated"));
+    assert_equal!(clone.read(), Value::from("string"));
+    assert_equal!(pointer.refs(), 1);
+    assert_equal!(clone.refs(), 1);
+}
+
+#[test]
+fn test_atomic_unique_pointer_get_mut() {
+    let mut pointer = AtomicUniquePointer::from(Value::Byte(1));
+    *pointer.get_mut().unwrap() = Value::Byte(2);
+    assert_equal!(pointer.read(), Value::Byte(2));
+
+    let clone = pointer.clone();
+    assert_equal!(pointer.get_mut(), None);
+    drop(clone);
+    assert_equal!(pointer.get_mut(), Some(&mut Value::Byte(2)));
+    assert_equal!(AtomicUniquePointer::<Value>::null().get_mut(), None);
+}
+
+#[test]
+fn test_atomic_unique_pointer_dealloc_soft() {
+    let (mut pointer, drops) = tracked(1);
+    let clone = pointer.clone();
+
+    pointer.dealloc(true);
+    assert_equal!(pointer.is_null(), true);
+    assert_equal!(drops.load(Ordering::SeqCst), 0);
+    assert_equal!(clone.refs(), 1);
+    assert_equal!(clone.value, 1);
+
+    drop(clone);
+    assert_equal!(drops.load(Ordering::SeqCst), 1);
+    pointer.dealloc(true);
+    assert_equal!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_atomic_unique_pointer_dealloc_hard() {
+    let (mut pointer, drops) = tracked(1);
+    pointer.dealloc(false);
+    assert_equal!(pointer.is_null(), true);
+    assert_equal!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+#[should_panic(expected = "is shared by 2 pointers")]
+fn test_atomic_unique_pointer_dealloc_hard_shared() {
+    let mut pointer = AtomicUniquePointer::from(Value::Byte(1));
+    let clone = pointer.clone();
+    pointer.dealloc(false);
+}
+
+#[test]
+fn test_atomic_unique_pointer_drops_value_once() {
+    let (pointer, drops) = tracked(1);
+    let clones = (0..10).map(|_| pointer.clone()).collect::<Vec<_>>();
+    drop(pointer);
+    assert_equal!(drops.load(Ordering::SeqCst), 0);
+    drop(clones);
+    assert_equal!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_atomic_unique_pointer_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<AtomicUniquePointer<String>>();
+    assert_send_sync::<AtomicUniquePointer<Vec<u64>>>();
+}
+
+#[test]
+fn test_atomic_unique_pointer_concurrent_clone_and_drop() {
+    const THREADS: usize = 8;
+    const CLONES: usize = 10_000;
+
+    let (pointer, drops) = tracked(42);
+    let barrier = Arc::new(Barrier::new(THREADS));
+    let threads = (0..THREADS)
+        .map(|_| {
+            let pointer = pointer.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                let mut sum = 0;
+                for _ in 0..CLONES {
+                    let clone = pointer.clone();
+                    sum += clone.value;
+                    drop(clone);
+                }
+                sum
+            })
+        })
+        .collect::<Vec<_>>();
+    for thread in threads {
+        assert_equal!(thread.join().unwrap(), 42 * CLONES);
+    }
+    assert_equal!(pointer.refs(), 1);
+    assert_equal!(drops.load(Ordering::SeqCst), 0);
+    drop(pointer);
+    assert_equal!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_atomic_unique_pointer_concurrent_last_drop() {
+    for _ in 0..100 {
+        let (pointer, drops) = tracked(7);
+        let barrier = Arc::new(Barrier::new(4));
+        let threads = (0..4)
+            .map(|_| {
+                let pointer = pointer.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    let clones = (0..100).map(|_| pointer.clone()).collect::<Vec<_>>();
+                    assert!(clones.iter().all(|clone| clone.value == 7));
+                })
+            })
+            .collect::<Vec<_>>();
+        drop(pointer);
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_equal!(drops.load(Ordering::SeqCst), 1);
+    }
+}
+
+#[test]
+fn test_atomic_unique_pointer_concurrent_readers_and_writer() {
+    let shared = AtomicUniquePointer::from(String::from("initial"));
+    let readers = (0..4)
+        .map(|_| {
+            let pointer = shared.clone();
+            thread::spawn(move || {
+                for _ in 0..1_000 {
+                    assert_equal!(pointer.read(), "initial");
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut writer = shared.clone();
+    let writer = thread::spawn(move || {
+        for n in 0..1_000 {
+            writer.write(n.to_string());
+        }
+        writer.read()
+    });
+    assert_equal!(writer.join().unwrap(), "999");
+    for reader in readers {
+        reader.join().unwrap();
+    }
+    assert_equal!(shared.refs(), 1);
+}
@@ -0,0 +1,221 @@
+#![allow(unused)]
+use ds::*;
+use k9::assert_equal;
+
+fn values<'t>(tree: &BinaryTree<'t>) -> Vec<Value<'t>> {
+    let mut values = Vec::new();
+    let mut node = tree.find_min();
+    while let Some(current) = node {
+        values.push(current.item());
+        node = tree.find_next(&current.item());
+    }
+    values
+}
+
+#[test]
+fn test_avl_rotate_right() {
+    ///|||||||||||||||||||||||||||||||||||||||||||||\\\
+    ///                                             \\\
+    ///          D                  B               \\\
+    ///         / \                / \              \\\
+    ///        B   E      =>      A   D             \\\
+    ///       / \                    / \            \\\
+    ///      A   C                  C   E           \\\
+    ///                                             \\\
+    let mut tree = BinaryTree::new(Value::from("D"));
+    let node_a = tree.node(Value::from("A"));
+    let node_b = tree.node(Value::from("B"));
+    let node_c = tree.node(Value::from("C"));
+    let node_e = tree.node(Value::from("E"));
+    let root = tree.root_mut().unwrap();
+    root.set_left(node_b);
+    root.set_right(node_e);
+    node_b.set_left(node_a);
+    node_b.set_right(node_c);
+
+    root.rotate_right();
+
+    let root = tree.root().unwrap();
+    assert_equal!(root.value(), Some(Value::from("B")));
+    assert_equal!(root.left_value(), Some(Value::from("A")));
+    assert_equal!(root.right_value(), Some(Value::from("D")));
+    let right = root.right().unwrap();
+    assert_equal!(right.left_value(), Some(Value::from("C")));
+    assert_equal!(right.right_value(), Some(Value::from("E")));
+    assert_equal!(right.parent_value(), Some(Value::from("B")));
+    assert_equal!(right.left().unwrap().parent_value(), Some(Value::from("D")));
+    assert_equal!(root.height(), 2);
+    assert_equal!(right.height(), 1);
+    assert_equal!(tree.len(), 5);
+}
+
+#[test]
+fn test_avl_rotate_left() {
+    ///|||||||||||||||||||||||||||||||||||||||||||||\\\
+    ///                                             \\\
+    ///        B                      D             \\\
+    ///       / \                    / \            \\\
+    ///      A   D        =>        B   E           \\\
+    ///         / \                / \              \\\
+    ///        C   E              A   C             \\\
+    ///                                             \\\
+    let mut tree = BinaryTree::new(Value::from("B"));
+    let node_a = tree.node(Value::from("A"));
+    let node_c = tree.node(Value::from("C"));
+    let node_d = tree.node(Value::from("D"));
+    let node_e = tree.node(Value::from("E"));
+    let root = tree.root_mut().unwrap();
+    root.set_left(node_a);
+    root.set_right(node_d);
+    node_d.set_left(node_c);
+    node_d.set_right(node_e);
+
+    root.rotate_left();
+
+    let root = tree.root().unwrap();
+    assert_equal!(root.value(), Some(Value::from("D")));
+    assert_equal!(root.left_value(), Some(Value::from("B")));
+    assert_equal!(root.right_value(), Some(Value::from("E")));
+    let left = root.left().unwrap();
+    assert_equal!(left.left_value(), Some(Value::from("A")));
+    assert_equal!(left.right_value(), Some(Value::from("C")));
+    assert_equal!(left.parent_value(), Some(Value::from("D")));
+    assert_equal!(left.right().unwrap().parent_value(), Some(Value::from("B")));
+    assert_equal!(root.height(), 2);
+    assert_equal!(left.height(), 1);
+    assert_equal!(tree.len(), 5);
+}
+
+#[test]
+fn test_avl_rotations_keep_ancestor_refs() {
+    let mut tree = BinaryTree::new(Value::from("F"));
+    let node_b = tree.node(Value::from("B"));
+    let node_a = tree.node(Value::from("A"));
+    let node_d = tree.node(Value::from("D"));
+    let node_c = tree.node(Value::from("C"));
+    let node_e = tree.node(Value::from("E"));
+    let root = tree.root_mut().unwrap();
+    root.set_left(node_b);
+    node_b.set_left(node_a);
+    node_b.set_right(node_d);
+    node_d.set_left(node_c);
+    node_d.set_right(node_e);
+
+    let refs = tree.root().unwrap().refs();
+    node_b.rotate_left();
+    assert_equal!(tree.root().unwrap().refs(), refs);
+    node_b.rotate_right();
+    assert_equal!(tree.root().unwrap().refs(), refs);
+    assert_equal!(node_b.value(), Some(Value::from("B")));
+    assert_equal!(node_b.right_value(), Some(Value::from("D")));
+
+    // each node below the root counts itself and the link from its parent
+    let refs = tree.root().unwrap().iter_preorder().skip(1).map(Node::refs);
+    assert_equal!(refs.collect::<Vec<_>>(), vec![2; 5]);
+}
+
+#[test]
+fn test_avl_skew() {
+    let mut tree = BinaryTree::new(Value::from("B"));
+    let node_a = tree.node(Value::from("A"));
+    let root = tree.root_mut().unwrap();
+    assert_equal!(root.skew(), 0);
+    root.set_left(node_a);
+    assert_equal!(root.skew(), -1);
+    assert_equal!(root.height(), 1);
+    assert_equal!(node_a.skew(), 0);
+}
+
+#[test]
+fn test_avl_insert_ascending() {
+    let mut tree = BinaryTree::nil();
+    for value in 0..100u64 {
+        tree.insert(Value::from(value));
+        assert_equal!(tree.root().unwrap().is_avl(), true);
+    }
+    assert_equal!(tree.len(), 100);
+    assert_equal!(tree.root().unwrap().height() <= 9, true);
+    assert_equal!(values(&tree), (0..100u64).map(Value::from).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_avl_insert_descending() {
+    let mut tree = BinaryTree::nil();
+    for value in (0..100u64).rev() {
+        tree.insert(Value::from(value));
+        assert_equal!(tree.root().unwrap().is_avl(), true);
+    }
+    assert_equal!(tree.root().unwrap().height() <= 9, true);
+    assert_equal!(values(&tree), (0..100u64).map(Value::from).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_avl_subtree_insert_before() {
+    ///|||||||||||||||||||||||||||||||||||||||||||||\\\
+    ///                                             \\\
+    ///  inserting C, B, A each before the first    \\\
+    ///  node rebalances into                       \\\
+    ///                                             \\\
+    ///                     B                       \\\
+    ///                    / \                      \\\
+    ///                   A   C                     \\\
+    ///                                             \\\
+    let mut tree = BinaryTree::new(Value::from("C"));
+    for value in ["B", "A"] {
+        let node = tree.node(Value::from(value));
+        let mut first = tree.root_mut().unwrap();
+        while let Some(left) = first.left_mut() {
+            first = left;
+        }
+        first.subtree_insert_before(node);
+    }
+
+    let root = tree.root().unwrap();
+    assert_equal!(root.value(), Some(Value::from("B")));
+    assert_equal!(root.left_value(), Some(Value::from("A")));
+    assert_equal!(root.right_value(), Some(Value::from("C")));
+    assert_equal!(root.is_avl(), true);
+    assert_equal!(root.height(), 1);
+}
+
+#[test]
+fn test_avl_delete() {
+    let mut tree = BinaryTree::nil();
+    for value in 0..64u64 {
+        tree.insert(Value::from(value));
+    }
+    for value in (0..64u64).filter(|value| value % 3 != 0) {
+        assert_equal!(tree.delete(&Value::from(value)), Some(Value::from(value)));
+        assert_equal!(tree.root().unwrap().is_avl(), true);
+    }
+    assert_equal!(
+        values(&tree),
+        (0..64u64).filter(|value| value % 3 == 0).map(Value::from).collect::<Vec<_>>()
+    );
+    assert_equal!(tree.len(), 22);
+}
+
+#[test]
+fn test_avl_insert_from_child_rotating_the_root() {
+    ///|||||||||||||||||||||||||||||||||||||||||||||\\\
+    ///                                             \\\
+    ///            4                    2           \\\
+    ///           / \                  / \          \\\
+    ///          2   5      =>        1   4         \\\
+    ///         / \                  /   / \        \\\
+    ///        1   3                0   3   5       \\\
+    ///                                             \\\
+    let mut tree = BinaryTree::new(Value::Byte(4));
+    for n in [2, 5, 1, 3] {
+        tree.insert(Value::Byte(n));
+    }
+    let node_2 = tree.root_mut().unwrap().find_mut(&Value::Byte(2)).unwrap();
+
+    let node_0 = node_2.insert(Value::Byte(0));
+
+    assert_equal!(node_0.value(), Some(Value::Byte(0)));
+    assert_equal!(node_0.parent_value(), Some(Value::Byte(1)));
+    assert_equal!(tree.root().unwrap().value(), Some(Value::Byte(2)));
+    assert_equal!(tree.root().unwrap().is_avl(), true);
+    assert_equal!(values(&tree), (0..6).map(Value::Byte).collect::<Vec<_>>());
+}
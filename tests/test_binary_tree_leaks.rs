@@ -0,0 +1,114 @@
+#![allow(unused)]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use ds::*;
+use k9::assert_equal;
+
+/// `Counting` counts the allocations alive on each thread, such that
+/// tests running in parallel do not count each other's allocations
+struct Counting;
+
+thread_local! {
+    static ALIVE: Cell<isize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALIVE.set(ALIVE.get() + 1);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALIVE.set(ALIVE.get() + 1);
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALIVE.set(ALIVE.get() - 1);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// `leaked` returns the number of allocations made by `f` which are
+/// still alive once it returns.
+///
+/// `f` runs once beforehand such that the allocations kept by the
+/// test harness, e.g.: the buffer capturing the output printed by
+/// the "debug" feature, are not counted.
+fn leaked(f: impl Fn()) -> isize {
+    f();
+    let before = ALIVE.get();
+    f();
+    ALIVE.get() - before
+}
+
+#[test]
+fn test_binary_tree_drop_single_node_does_not_leak() {
+    let leaks = leaked(|| {
+        let tree = BinaryTree::new(Value::Byte(1));
+        assert_equal!(tree.len(), 1);
+    });
+    assert_equal!(leaks, 0);
+}
+
+#[test]
+fn test_binary_tree_drop_linked_nodes_does_not_leak() {
+    let leaks = leaked(|| {
+        let mut tree = BinaryTree::new(Value::Byte(2));
+        let node_a = tree.node(Value::Byte(1));
+        let node_c = tree.node(Value::Byte(3));
+        let root = tree.root_mut().unwrap();
+        root.set_left(node_a);
+        root.set_right(node_c);
+        assert_equal!(tree.len(), 3);
+    });
+    assert_equal!(leaks, 0);
+}
+
+#[test]
+fn test_binary_tree_drop_relinked_nodes_does_not_leak() {
+    let leaks = leaked(|| {
+        let mut tree = BinaryTree::new(Value::Byte(2));
+        let node_a = tree.node(Value::Byte(1));
+        let node_b = tree.node(Value::Byte(0));
+        let root = tree.root_mut().unwrap();
+        root.set_left(node_a);
+        root.set_left(node_a);
+        node_a.set_left(node_b);
+        assert_equal!(tree.len(), 3);
+        // node A counts itself and the link from the root once
+        assert_equal!(node_a.refs(), 2);
+    });
+    assert_equal!(leaks, 0);
+}
+
+#[test]
+fn test_binary_tree_drop_after_inserts_does_not_leak() {
+    let leaks = leaked(|| {
+        let mut tree = BinaryTree::new(Value::UInt(0));
+        for n in 1..100u64 {
+            tree.insert(Value::UInt(n * 1000));
+        }
+        assert_equal!(tree.len(), 100);
+    });
+    assert_equal!(leaks, 0);
+}
+
+#[test]
+fn test_binary_tree_drop_after_inserts_and_deletes_does_not_leak() {
+    let leaks = leaked(|| {
+        let mut tree = BinaryTree::new(Value::UInt(0));
+        for n in 1..100u64 {
+            tree.insert(Value::UInt(n * 1000));
+        }
+        for n in 0..100u64 {
+            assert_equal!(tree.delete(&Value::UInt(n * 1000)), Some(Value::UInt(n * 1000)));
+        }
+        assert_equal!(tree.len(), 0);
+    });
+    assert_equal!(leaks, 0);
+}
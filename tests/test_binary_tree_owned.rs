@@ -0,0 +1,116 @@
+#![allow(unused)]
+use ds::*;
+use k9::assert_equal;
+
+pub fn tree<'t>() -> BinaryTree<'t> {
+    ///|||||||||||||||||||||||||||||||||||||||||||||\\\
+    ///                                             \\\
+    ///                     A                       \\\
+    ///                    / \                      \\\
+    ///                   B   C                     \\\
+    ///                  / \                        \\\
+    ///                 D   E                       \\\
+    ///                                             \\\
+    let mut tree = BinaryTree::new(Value::from("A"));
+    let node_b = tree.node(Value::from("B"));
+    let node_c = tree.node(Value::from("C"));
+    let node_d = tree.node(Value::from("D"));
+    let node_e = tree.node(Value::from("E"));
+
+    node_b.set_left(node_d);
+    node_b.set_right(node_e);
+
+    let root = tree.root_mut().unwrap();
+    root.set_left(node_b);
+    root.set_right(node_c);
+    tree
+}
+
+#[test]
+fn test_binary_tree_nil() {
+    let tree = BinaryTree::nil();
+
+    assert_equal!(tree.is_empty(), true);
+    assert_equal!(tree.len(), 0);
+    assert_equal!(tree.root(), None);
+}
+
+#[test]
+fn test_binary_tree_new() {
+    let tree = BinaryTree::new(Value::from("A"));
+
+    assert_equal!(tree.is_empty(), false);
+    assert_equal!(tree.len(), 1);
+    assert_equal!(tree.root().map(|root| root.item()), Some(Value::from("A")));
+}
+
+#[test]
+fn test_binary_tree_owns_linked_nodes() {
+    let tree = tree();
+
+    assert_equal!(tree.len(), 5);
+
+    let root = tree.root().unwrap();
+    assert_equal!(root.value(), Some(Value::from("A")));
+    assert_equal!(root.left_value(), Some(Value::from("B")));
+    assert_equal!(root.right_value(), Some(Value::from("C")));
+    assert_equal!(root.left().unwrap().left_value(), Some(Value::from("D")));
+    assert_equal!(root.left().unwrap().right_value(), Some(Value::from("E")));
+    assert_equal!(root.left().unwrap().left().unwrap().parent_value(), Some(Value::from("B")));
+}
+
+#[test]
+fn test_binary_tree_survives_moves() {
+    let trees = vec![tree(), tree()];
+    let tree = trees.into_iter().last().unwrap();
+
+    assert_equal!(tree.len(), 5);
+    assert_equal!(tree.root().unwrap().subtree_first().value(), Some(Value::from("D")));
+}
+
+#[test]
+fn test_binary_tree_successor_and_predecessor() {
+    let tree = tree();
+    let root = tree.root().unwrap();
+    let node_b = root.left().unwrap();
+    let node_d = node_b.left().unwrap();
+
+    assert_equal!(node_d.successor().value(), Some(Value::from("B")));
+    assert_equal!(node_b.successor().value(), Some(Value::from("E")));
+    assert_equal!(root.predecessor().value(), Some(Value::from("E")));
+    assert_equal!(node_b.predecessor().value(), Some(Value::from("D")));
+}
+
+#[test]
+fn test_binary_tree_subtree_delete_frees_detached_leaf() {
+    let mut tree = tree();
+
+    let root = tree.root_mut().unwrap();
+    tree.subtree_delete(root);
+
+    assert_equal!(tree.len(), 4);
+    let root = tree.root().unwrap();
+    assert_equal!(root.value(), Some(Value::from("E")));
+    assert_equal!(root.left().unwrap().right(), None);
+
+    let node_c = tree.root_mut().unwrap().right_mut().unwrap();
+    tree.subtree_delete(node_c);
+    assert_equal!(tree.len(), 3);
+
+    let root = tree.root().unwrap();
+    assert_equal!(root.value(), Some(Value::from("B")));
+    assert_equal!(root.left_value(), Some(Value::from("D")));
+    assert_equal!(root.right_value(), Some(Value::from("E")));
+    assert_equal!(root.is_avl(), true);
+}
+
+#[test]
+fn test_binary_tree_subtree_delete_single_root() {
+    let mut tree = BinaryTree::new(Value::from("A"));
+
+    let root = tree.root_mut().unwrap();
+    tree.subtree_delete(root);
+
+    assert_equal!(tree.is_empty(), true);
+    assert_equal!(tree.len(), 0);
+}
@@ -3,6 +3,7 @@ use ds::*;
 use k9::assert_equal;
 
 struct MitCourseWareTree<'c, 't> {
+    pub owner: BinaryTree<'t>,
     pub node_a: &'c mut Node<'t>,
     pub node_b: &'c mut Node<'t>,
     pub node_c: &'c mut Node<'t>,
@@ -31,12 +32,14 @@ impl<'c, 't> MitCourseWareTree<'c, 't> {
         // Scenario: Create nodes and test the equality of its items
         //
         // Given that I create disconnected nodes with values A through F
-        let mut node_a = Node::new(Value::from("A"));
-        let mut node_b = Node::new(Value::from("B"));
-        let mut node_c = Node::new(Value::from("C"));
-        let mut node_d = Node::new(Value::from("D"));
-        let mut node_e = Node::new(Value::from("E"));
-        let mut node_f = Node::new(Value::from("F"));
+        // in a tree rooted at node A, which frees them when dropped
+        let mut owner = BinaryTree::new(Value::from("A"));
+        let node_a = owner.root_mut().unwrap();
+        let node_b = owner.node(Value::from("B"));
+        let node_c = owner.node(Value::from("C"));
+        let node_d = owner.node(Value::from("D"));
+        let node_e = owner.node(Value::from("E"));
+        let node_f = owner.node(Value::from("F"));
 
         // Then each node has its corresponding value
         assert_equal!(node_a.value(), Some(Value::from("A")));
@@ -50,24 +53,24 @@ impl<'c, 't> MitCourseWareTree<'c, 't> {
         /// Scenario: Connect nodes and check the equality of the items parents ///
         ///                                                                     ///
         /// Given that I set D as in left of B                                  ///
-        node_b.set_left(&mut node_d);
+        node_b.set_left(node_d);
         ///
         ///                                                                     ///
         /// And that I set B as in left of A before setting E as right of B     ///
         /// so as to test that memory references are set correctly*             ///
-        node_a.set_left(&mut node_b);
+        node_a.set_left(node_b);
         ///
         ///                                                                     ///
         /// And that I set C as left of A                                       ///
-        node_a.set_right(&mut node_c);
+        node_a.set_right(node_c);
         ///
         ///                                                                     ///
         /// And that I set E in right of B*                                     ///
-        node_b.set_right(&mut node_e);
+        node_b.set_right(node_e);
         ///
         ///                                                                     ///
         /// And that I set F in left of D                                       ///
-        node_d.set_left(&mut node_f);
+        node_d.set_left(node_f);
         ///
         ///                                                                     ///
         /// Then the parent of node B parent has value "A"                      ///
@@ -96,24 +99,24 @@ impl<'c, 't> MitCourseWareTree<'c, 't> {
         /// Given that all nodes have been connected         ///
         ///                                                  ///
         /// Then the parent of node B is node A              ///
-        assert_equal!(node_b.parent(), Some(&node_a));
+        assert_equal!(node_b.parent(), Some(&*node_a));
         ///
         ///                                                  ///
         /// And the parent of node C is node A               ///
-        assert_equal!(node_c.parent(), Some(&node_a));
+        assert_equal!(node_c.parent(), Some(&*node_a));
         ///
         ///                                                  ///
         ///                                                  ///
         /// And the parent of node D is node B               ///
-        assert_equal!(node_d.parent(), Some(&node_b));
+        assert_equal!(node_d.parent(), Some(&*node_b));
         ///
         ///                                                  ///
         /// And the parent of node E is node B               ///
-        assert_equal!(node_e.parent(), Some(&node_b));
+        assert_equal!(node_e.parent(), Some(&*node_b));
         ///
         ///                                                  ///
         /// And the parent of node F is node D               ///
-        assert_equal!(node_f.parent(), Some(&node_d));
+        assert_equal!(node_f.parent(), Some(&*node_d));
         ///
         ///                                                  ///
 
@@ -124,11 +127,11 @@ impl<'c, 't> MitCourseWareTree<'c, 't> {
         /// Given that all nodes have been connected                                               ///
         ///                                                                                        ///
         /// Then the left of node A is node B                                                      ///
-        assert_equal!(node_a.left(), Some(&node_b));
+        assert_equal!(node_a.left(), Some(&*node_b));
         ///
         ///                                                                                        ///
         /// And the right of node A is node C                                                      ///
-        assert_equal!(node_a.right(), Some(&node_c));
+        assert_equal!(node_a.right(), Some(&*node_c));
         ///
         ///                                                                                        ///
         /// And node A is the root node (no parent)                                                ///
@@ -137,15 +140,15 @@ impl<'c, 't> MitCourseWareTree<'c, 't> {
         ///                                                                                        ///
         ///                                                                                        ///
         /// And the left of node B is node D                                                       ///
-        assert_equal!(node_b.left(), Some(&node_d));
+        assert_equal!(node_b.left(), Some(&*node_d));
         ///
         ///                                                                                        ///
         /// And the right of node B is node E                                                      ///
-        assert_equal!(node_b.right(), Some(&node_e));
+        assert_equal!(node_b.right(), Some(&*node_e));
         ///
         ///                                                                                        ///
         /// And the parent of node B is node A                                                     ///
-        assert_equal!(node_b.parent(), Some(&node_a));
+        assert_equal!(node_b.parent(), Some(&*node_a));
         ///
         ///                                                                                        ///
         /// And node B has no grand-parent                                                         ///
@@ -158,22 +161,22 @@ impl<'c, 't> MitCourseWareTree<'c, 't> {
         assert_equal!(node_c.right(), None);
         ///
         ///                                                                                        ///
-        assert_equal!(node_c.parent(), Some(&node_a));
+        assert_equal!(node_c.parent(), Some(&*node_a));
         ///
         ///                                                                                        ///
         assert_equal!(node_c.parent().unwrap().parent(), None);
         ///
         ///                                                                                        ///
-        assert_equal!(node_d.left(), Some(&node_f));
+        assert_equal!(node_d.left(), Some(&*node_f));
         ///
         ///                                                                                        ///
         assert_equal!(node_d.right(), None);
         ///
         ///                                                                                        ///
-        assert_equal!(node_d.parent(), Some(&node_b));
+        assert_equal!(node_d.parent(), Some(&*node_b));
         ///
         ///                                                                                        ///
-        assert_equal!(node_d.parent().unwrap().parent(), Some(&node_a));
+        assert_equal!(node_d.parent().unwrap().parent(), Some(&*node_a));
         ///
         ///                                                                                        ///
         assert_equal!(node_d.parent().unwrap().parent().unwrap().parent(), None);
@@ -185,28 +188,30 @@ impl<'c, 't> MitCourseWareTree<'c, 't> {
         assert_equal!(node_f.right(), None);
         ///
         ///                                                                                        ///
-        assert_equal!(node_f.parent(), Some(&node_d));
+        assert_equal!(node_f.parent(), Some(&*node_d));
         ///
         ///                                                                                        ///
-        assert_equal!(node_f.parent().unwrap().parent(), Some(&node_b));
+        assert_equal!(node_f.parent().unwrap().parent(), Some(&*node_b));
         ///
         ///                                                                                        ///
-        assert_equal!(node_f.parent().unwrap().parent().unwrap().parent(), Some(&node_a));
+        assert_equal!(node_f.parent().unwrap().parent().unwrap().parent(), Some(&*node_a));
         ///
         ///                                                                                        ///
         assert_equal!(node_f.parent().unwrap().parent().unwrap().parent().unwrap().parent(), None);
         ///
-        ///                                                                                        ///
-        assert_equal!(node_a.refs(), 9);
+        /// Each node counts its own reference plus the link from its                              ///
+        /// parent, as the parent links are weak and the references of a                           ///
+        /// node no longer include those of its descendants                                        ///
+        assert_equal!(node_a.refs(), 1);
         ///
         ///                                                                                        ///
-        assert_equal!(node_b.refs(), 8);
+        assert_equal!(node_b.refs(), 2);
         ///
         ///                                                                                        ///
         assert_equal!(node_c.refs(), 2);
         ///
         ///                                                                                        ///
-        assert_equal!(node_d.refs(), 4);
+        assert_equal!(node_d.refs(), 2);
         ///
         ///                                                                                        ///
         assert_equal!(node_e.refs(), 2);
@@ -238,18 +243,19 @@ impl<'c, 't> MitCourseWareTree<'c, 't> {
         // assert_equal!(node_f.depth(), 3);
 
         let tree = MitCourseWareTree {
+            owner,
             #[rustfmt::skip]
-            node_a: unsafe {std::mem::transmute::<&mut Node<'t>, &'c mut Node<'t>>(&mut node_a)},
+            node_a: unsafe {std::mem::transmute::<&mut Node<'t>, &'c mut Node<'t>>(node_a)},
             #[rustfmt::skip]
-            node_b: unsafe {std::mem::transmute::<&mut Node<'t>, &'c mut Node<'t>>(&mut node_b)},
+            node_b: unsafe {std::mem::transmute::<&mut Node<'t>, &'c mut Node<'t>>(node_b)},
             #[rustfmt::skip]
-            node_c: unsafe {std::mem::transmute::<&mut Node<'t>, &'c mut Node<'t>>(&mut node_c)},
+            node_c: unsafe {std::mem::transmute::<&mut Node<'t>, &'c mut Node<'t>>(node_c)},
             #[rustfmt::skip]
-            node_d: unsafe {std::mem::transmute::<&mut Node<'t>, &'c mut Node<'t>>(&mut node_d)},
+            node_d: unsafe {std::mem::transmute::<&mut Node<'t>, &'c mut Node<'t>>(node_d)},
             #[rustfmt::skip]
-            node_e: unsafe {std::mem::transmute::<&mut Node<'t>, &'c mut Node<'t>>(&mut node_e)},
+            node_e: unsafe {std::mem::transmute::<&mut Node<'t>, &'c mut Node<'t>>(node_e)},
             #[rustfmt::skip]
-            node_f: unsafe {std::mem::transmute::<&mut Node<'t>, &'c mut Node<'t>>(&mut node_f)},
+            node_f: unsafe {std::mem::transmute::<&mut Node<'t>, &'c mut Node<'t>>(node_f)},
         };
         assert_equal!(tree.node_c.height(), 0); // leaf
         assert_equal!(tree.node_e.height(), 0); // leaf
@@ -352,8 +358,8 @@ fn test_tree_operation_successor() {
 fn test_tree_operation_successor_of_c() {
     let mut tree = MitCourseWareTree::initial_state();
 
-    let mut node_g = Node::new(Value::from("G"));
-    tree.node_c.set_left(&mut node_g);
+    let node_g = tree.owner.node(Value::from("G"));
+    tree.node_c.set_left(node_g);
 
     assert_equal!(tree.node_c.successor(), &node_g);
 }
@@ -390,8 +396,8 @@ fn test_tree_operation_successor_mut() {
 fn test_tree_operation_successor_mut_of_c() {
     let mut tree = MitCourseWareTree::initial_state();
 
-    let mut node_g = Node::new(Value::from("G"));
-    tree.node_c.set_left(&mut node_g);
+    let mut node_g = tree.owner.node(Value::from("G"));
+    tree.node_c.set_left(node_g);
 
     assert_equal!(tree.node_c.successor_mut(), &mut node_g);
 }
@@ -400,18 +406,18 @@ fn test_tree_operation_successor_mut_of_c() {
 fn test_tree_operation_subtree_insert_after_node_when_node_left_is_null() {
     let mut tree = MitCourseWareTree::initial_state();
 
-    let mut node_g = Node::new(Value::from("G"));
-    tree.node_c.subtree_insert_after(&mut node_g);
+    let node_g = tree.owner.node(Value::from("G"));
+    tree.node_c.subtree_insert_after(node_g);
 
-    assert_equal!(node_g.parent(), Some(&tree.node_c.clone()));
+    assert_equal!(node_g.parent(), Some(&*tree.node_c));
 }
 
 #[test]
 fn test_tree_operation_subtree_insert_after_node_when_node_right_is_non_null() {
     let mut tree = MitCourseWareTree::initial_state();
 
-    let mut node_g = Node::new(Value::from("G"));
-    tree.node_a.subtree_insert_after(&mut node_g);
+    let node_g = tree.owner.node(Value::from("G"));
+    tree.node_a.subtree_insert_after(node_g);
 
     assert_equal!(node_g.parent(), tree.node_a.right());
     assert_equal!(node_g.parent(), Some(tree.node_c.as_ref()));
@@ -432,8 +438,8 @@ fn test_tree_operation_predecessor() {
 fn test_tree_operation_predecessor_of_g_as_right_of_e() {
     let mut tree = MitCourseWareTree::initial_state();
 
-    let mut node_g = Node::new(Value::from("G"));
-    tree.node_e.set_right(&mut node_g);
+    let node_g = tree.owner.node(Value::from("G"));
+    tree.node_e.set_right(node_g);
 
     assert_equal!(node_g.predecessor(), &tree.node_e);
 }
@@ -453,8 +459,8 @@ fn test_tree_operation_predecessor_mut() {
 fn test_tree_operation_predecessor_mut_of_g_as_right_of_e() {
     let mut tree = MitCourseWareTree::initial_state();
 
-    let mut node_g = Node::new(Value::from("G"));
-    tree.node_e.set_right(&mut node_g);
+    let node_g = tree.owner.node(Value::from("G"));
+    tree.node_e.set_right(node_g);
 
     assert_equal!(node_g.predecessor_mut(), &mut tree.node_e);
 }
@@ -484,12 +490,12 @@ fn test_tree_operation_subtree_delete_leaf_nodes() {
     // Given the test tree in its initial state
     let mut tree = MitCourseWareTree::initial_state();
 
-    // Then node D has 4 references
-    assert_equal!(tree.node_d.refs(), 4);
-    assert_equal!(tree.node_a.refs(), 9);
-    assert_equal!(tree.node_b.refs(), 8);
+    // Then node D has 2 references, its own and the link from node B
+    assert_equal!(tree.node_d.refs(), 2);
+    assert_equal!(tree.node_a.refs(), 1);
+    assert_equal!(tree.node_b.refs(), 2);
     assert_equal!(tree.node_c.refs(), 2);
-    assert_equal!(tree.node_d.refs(), 4);
+    assert_equal!(tree.node_d.refs(), 2);
     assert_equal!(tree.node_e.refs(), 2);
 
     // When I subtree_delete node F
@@ -501,16 +507,21 @@ fn test_tree_operation_subtree_delete_leaf_nodes() {
     // And node D has no node in its left
     assert_equal!(tree.node_d.left(), None);
 
-    // And node D has 3 references
-    assert_equal!(tree.node_d.left(), None);
+    // And node D keeps its 2 references
+    assert_equal!(tree.node_d.refs(), 2);
 
-    // And the references of all ancestors of D are decremented
-    assert_equal!(tree.node_a.refs(), 8);
-    assert_equal!(tree.node_b.refs(), 7);
+    // And the references of all ancestors of F remain unchanged, as
+    // they do not count the references of their descendants
+    assert_equal!(tree.node_a.refs(), 1);
+    assert_equal!(tree.node_b.refs(), 2);
 
     // And the references of the other leaf nodes remains unchanged
     assert_equal!(tree.node_c.refs(), 2);
     assert_equal!(tree.node_e.refs(), 2);
+
+    // And node F links back into the left of node D such that the
+    // tree frees it along with the other nodes
+    tree.node_d.set_left(tree.node_f);
 }
 
 #[test]
@@ -518,8 +529,8 @@ fn test_tree_operation_subtree_delete_root_node() {
     // Given the test tree in its initial state
     let mut tree = MitCourseWareTree::initial_state();
 
-    // Then node A has 8 references
-    assert_equal!(tree.node_a.refs(), 9);
+    // Then node A has 1 reference, as the root is not linked by a parent
+    assert_equal!(tree.node_a.refs(), 1);
     // And node B is in the left of node A
     assert_equal!(tree.node_a.left(), Some(tree.node_b.as_ref()));
     // And node C is in the right of node A
@@ -537,11 +548,16 @@ fn test_tree_operation_subtree_delete_root_node() {
     // And node E becomes node A
     assert_equal!(tree.node_a.value(), Some(Value::from("E")));
 
-    // And node E (which has become A) has 8 references
-    assert_equal!(tree.node_a.refs(), 8);
+    // And node E (which has become A) keeps the 1 reference of the
+    // root, as rebalancing only relinks the nodes below it
+    assert_equal!(tree.node_a.refs(), 1);
 
     // And node B is in the left of node E
     assert_equal!(tree.node_a.left(), Some(tree.node_b.as_ref()));
     // And node C is in the right of node E
     assert_equal!(tree.node_a.right(), Some(tree.node_c.as_ref()));
+
+    // And node A (which has become E) links into the left of node C
+    // such that the tree frees it along with the other nodes
+    tree.node_c.set_left(tree.node_e);
 }
@@ -0,0 +1,170 @@
+#![allow(unused)]
+use ds::*;
+use k9::assert_equal;
+
+pub fn tree<'t>() -> BinaryTree<'t> {
+    ///|||||||||||||||||||||||||||||||||||||||||||||\\\
+    ///                                             \\\
+    ///                     5                       \\\
+    ///                    / \                      \\\
+    ///                   /   \                     \\\
+    ///                  3     8                    \\\
+    ///                 / \   / \                   \\\
+    ///                1   4 7   9                  \\\
+    ///                                             \\\
+    let mut tree = BinaryTree::nil();
+    for value in [5u8, 3, 8, 1, 4, 7, 9] {
+        tree.insert(Value::from(value));
+    }
+    tree
+}
+
+fn values<'t>(tree: &BinaryTree<'t>) -> Vec<Value<'t>> {
+    let mut values = Vec::new();
+    let mut node = tree.find_min();
+    while let Some(current) = node {
+        values.push(current.item());
+        node = tree.find_next(&current.item());
+    }
+    values
+}
+
+#[test]
+fn test_bst_insert() {
+    let tree = tree();
+
+    assert_equal!(tree.len(), 7);
+    let root = tree.root().unwrap();
+    assert_equal!(root.value(), Some(Value::from(5u8)));
+    assert_equal!(root.left_value(), Some(Value::from(3u8)));
+    assert_equal!(root.right_value(), Some(Value::from(8u8)));
+    assert_equal!(root.left().unwrap().left_value(), Some(Value::from(1u8)));
+    assert_equal!(root.left().unwrap().right_value(), Some(Value::from(4u8)));
+    assert_equal!(root.right().unwrap().left_value(), Some(Value::from(7u8)));
+    assert_equal!(root.right().unwrap().right_value(), Some(Value::from(9u8)));
+}
+
+#[test]
+fn test_bst_insert_existing_key_replaces_item() {
+    let mut tree = tree();
+
+    let node = tree.insert(Value::from(4u8));
+
+    assert_equal!(node.value(), Some(Value::from(4u8)));
+    assert_equal!(tree.len(), 7);
+}
+
+#[test]
+fn test_bst_find() {
+    let tree = tree();
+
+    assert_equal!(tree.find(&Value::from(4u8)).map(|node| node.item()), Some(Value::from(4u8)));
+    assert_equal!(tree.find(&Value::from(9u8)).map(|node| node.item()), Some(Value::from(9u8)));
+    assert_equal!(tree.find(&Value::from(6u8)), None);
+    assert_equal!(BinaryTree::nil().find(&Value::from(6u8)), None);
+}
+
+#[test]
+fn test_bst_find_min_and_max() {
+    let tree = tree();
+
+    assert_equal!(tree.find_min().map(|node| node.item()), Some(Value::from(1u8)));
+    assert_equal!(tree.find_max().map(|node| node.item()), Some(Value::from(9u8)));
+    assert_equal!(BinaryTree::nil().find_min(), None);
+    assert_equal!(BinaryTree::nil().find_max(), None);
+}
+
+#[test]
+fn test_bst_find_next() {
+    let tree = tree();
+
+    assert_equal!(tree.find_next(&Value::from(1u8)).map(|node| node.item()), Some(Value::from(3u8)));
+    assert_equal!(tree.find_next(&Value::from(3u8)).map(|node| node.item()), Some(Value::from(4u8)));
+    assert_equal!(tree.find_next(&Value::from(4u8)).map(|node| node.item()), Some(Value::from(5u8)));
+    assert_equal!(tree.find_next(&Value::from(6u8)).map(|node| node.item()), Some(Value::from(7u8)));
+    assert_equal!(tree.find_next(&Value::from(0u8)).map(|node| node.item()), Some(Value::from(1u8)));
+    assert_equal!(tree.find_next(&Value::from(9u8)), None);
+}
+
+#[test]
+fn test_bst_find_prev() {
+    let tree = tree();
+
+    assert_equal!(tree.find_prev(&Value::from(9u8)).map(|node| node.item()), Some(Value::from(8u8)));
+    assert_equal!(tree.find_prev(&Value::from(5u8)).map(|node| node.item()), Some(Value::from(4u8)));
+    assert_equal!(tree.find_prev(&Value::from(7u8)).map(|node| node.item()), Some(Value::from(5u8)));
+    assert_equal!(tree.find_prev(&Value::from(6u8)).map(|node| node.item()), Some(Value::from(5u8)));
+    assert_equal!(tree.find_prev(&Value::from(10u8)).map(|node| node.item()), Some(Value::from(9u8)));
+    assert_equal!(tree.find_prev(&Value::from(1u8)), None);
+}
+
+#[test]
+fn test_bst_in_order() {
+    let tree = tree();
+
+    assert_equal!(
+        values(&tree),
+        [1u8, 3, 4, 5, 7, 8, 9].into_iter().map(Value::from).collect::<Vec<Value>>()
+    );
+}
+
+#[test]
+fn test_bst_delete() {
+    let mut tree = tree();
+
+    assert_equal!(tree.delete(&Value::from(3u8)), Some(Value::from(3u8)));
+    assert_equal!(tree.find(&Value::from(3u8)), None);
+    assert_equal!(tree.len(), 6);
+
+    assert_equal!(tree.delete(&Value::from(5u8)), Some(Value::from(5u8)));
+    assert_equal!(tree.find(&Value::from(5u8)), None);
+    assert_equal!(tree.len(), 5);
+
+    assert_equal!(tree.delete(&Value::from(6u8)), None);
+    assert_equal!(tree.len(), 5);
+
+    assert_equal!(
+        values(&tree),
+        [1u8, 4, 7, 8, 9].into_iter().map(Value::from).collect::<Vec<Value>>()
+    );
+}
+
+#[test]
+fn test_bst_delete_node_without_left_child() {
+    let mut tree = tree();
+
+    assert_equal!(tree.delete(&Value::from(1u8)), Some(Value::from(1u8)));
+    assert_equal!(tree.delete(&Value::from(3u8)), Some(Value::from(3u8)));
+
+    assert_equal!(
+        values(&tree),
+        [4u8, 5, 7, 8, 9].into_iter().map(Value::from).collect::<Vec<Value>>()
+    );
+}
+
+#[test]
+fn test_bst_delete_all() {
+    let mut tree = tree();
+
+    for value in [5u8, 3, 8, 1, 4, 7, 9] {
+        assert_equal!(tree.delete(&Value::from(value)), Some(Value::from(value)));
+    }
+    assert_equal!(tree.is_empty(), true);
+    assert_equal!(tree.len(), 0);
+}
+
+#[test]
+fn test_bst_on_node() {
+    let mut root = Node::nil();
+    for value in ["m", "c", "x", "a"] {
+        root.insert(Value::from(value));
+    }
+
+    assert_equal!(root.value(), Some(Value::from("m")));
+    assert_equal!(root.find(&Value::from("a")).map(|node| node.item()), Some(Value::from("a")));
+    assert_equal!(root.find_min().map(|node| node.item()), Some(Value::from("a")));
+    assert_equal!(root.find_max().map(|node| node.item()), Some(Value::from("x")));
+    assert_equal!(root.find_next(&Value::from("c")).map(|node| node.item()), Some(Value::from("m")));
+    assert_equal!(root.delete(&Value::from("m")), Some(Value::from("m")));
+    assert_equal!(root.value(), Some(Value::from("c")));
+}
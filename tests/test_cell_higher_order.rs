@@ -0,0 +1,113 @@
+#![allow(unused)]
+use ds::*;
+use k9::assert_equal;
+
+fn numbers<'c>() -> Cell<'c> {
+    (1..=5u64).map(Value::UInt).collect()
+}
+
+fn is_even(value: &Value) -> bool {
+    matches!(value, Value::UInt(n) if n % 2 == 0)
+}
+
+#[test]
+fn test_cell_map() {
+    let squares = numbers().map(|value| (value * value).unwrap());
+    assert_equal!(squares, [1u64, 4, 9, 16, 25].into_iter().collect::<Cell>());
+    assert_equal!(list!(a, "b").map(|value| value.kind().to_string()), list!("symbol", "string"));
+    assert_equal!(Cell::nil().map(|value| value.clone()).is_nil(), true);
+}
+
+#[test]
+fn test_cell_map_runtime_strings() {
+    let words = "lorem ipsum dolor".split(' ').map(String::from).collect::<Vec<_>>();
+    let cell = words.iter().map(|word| Value::from(word.as_str())).collect::<Cell>();
+    let lengths = cell.map(|value| match value {
+        Value::String(string) => string.len() as u64,
+        _ => 0,
+    });
+    assert_equal!(lengths, [5u64, 5, 5].into_iter().map(Value::UInt).collect::<Cell>());
+}
+
+#[test]
+fn test_cell_filter() {
+    let cell = numbers();
+    assert_equal!(cell.filter(is_even), [2u64, 4].into_iter().map(Value::UInt).collect::<Cell>());
+    assert_equal!(cell.filter(|_| false).is_nil(), true);
+    assert_equal!(cell.filter(|_| true), cell);
+    assert_equal!(cell.len(), 5);
+}
+
+#[test]
+fn test_cell_fold_left() {
+    let cell = list!(a, b, c);
+    let joined = cell.fold_left(String::new(), |acc, value| format!("({} {})", acc, value));
+    assert_equal!(joined, "((( a) b) c)");
+    assert_equal!(Cell::nil().fold_left(7, |acc, _| acc + 1), 7);
+}
+
+#[test]
+fn test_cell_fold_right() {
+    let cell = list!(a, b, c);
+    let joined = cell.fold_right(String::new(), |value, acc| format!("({} {})", value, acc));
+    assert_equal!(joined, "(a (b (c )))");
+    assert_equal!(Cell::nil().fold_right(7, |_, acc| acc + 1), 7);
+}
+
+#[test]
+fn test_cell_fold_right_rebuilds_list() {
+    let cell = list!(a, [b], "c");
+    let copy = cell.fold_right(Cell::nil(), |value, mut acc| {
+        if acc.is_nil() {
+            Cell::new(value.clone())
+        } else {
+            cons(value.clone(), &mut acc)
+        }
+    });
+    assert_equal!(copy, cell);
+}
+
+#[test]
+fn test_cell_reduce() {
+    let sum = numbers().reduce(|a, b| (&a + b).unwrap());
+    assert_equal!(sum, Some(Value::UInt(15)));
+    assert_equal!(list!(a).reduce(|_, _| Value::Nil), Some(Value::from(symbol("a"))));
+    assert_equal!(Cell::nil().reduce(|a, _| a), None);
+}
+
+#[test]
+fn test_cell_any_all() {
+    let cell = numbers();
+    assert_equal!(cell.any(is_even), true);
+    assert_equal!(cell.all(is_even), false);
+    assert_equal!(cell.all(Value::is_number), true);
+    assert_equal!(Cell::nil().any(|_| true), false);
+    assert_equal!(Cell::nil().all(|_| false), true);
+}
+
+#[test]
+fn test_cell_find() {
+    let cell = numbers();
+    assert_equal!(cell.find(is_even), Some(&Value::UInt(2)));
+    assert_equal!(cell.find(|value| value == &Value::UInt(9)), None);
+}
+
+#[test]
+fn test_mapcar() {
+    let negated = mapcar(|value| (-value).unwrap(), &list!(1i64, 2i64));
+    assert_equal!(negated, [Value::Int(-1), Value::Int(-2)].into_iter().collect::<Cell>());
+}
+
+#[test]
+fn test_remove_if() {
+    let odd = remove_if(is_even, &numbers());
+    assert_equal!(odd, [1u64, 3, 5].into_iter().map(Value::UInt).collect::<Cell>());
+    assert_equal!(remove_if(|_| true, &numbers()).is_nil(), true);
+}
+
+#[test]
+fn test_reduce() {
+    let product = reduce(|a, b| (&a * b).unwrap(), &numbers());
+    assert_equal!(product, Value::UInt(120));
+    assert_equal!(reduce(|a, _| a, &Cell::nil()), Value::Nil);
+}
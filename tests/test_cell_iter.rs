@@ -0,0 +1,109 @@
+#![allow(unused)]
+use ds::*;
+use k9::assert_equal;
+
+#[test]
+fn test_cell_iter() {
+    let cell = list!("head", "middle", 33u8, "tail");
+    assert_equal!(
+        cell.iter().collect::<Vec<_>>(),
+        vec![
+            &Value::from("head"),
+            &Value::from("middle"),
+            &Value::Byte(33),
+            &Value::from("tail"),
+        ]
+    );
+    assert_equal!(cell.iter().count(), cell.len());
+}
+
+#[test]
+fn test_cell_iter_nil() {
+    let cell = Cell::nil();
+    assert_equal!(cell.iter().next(), None);
+    assert_equal!(cell.into_iter().next(), None);
+}
+
+#[test]
+fn test_cell_iter_shares_added_cells() {
+    let mut head = Cell::new(Value::from("head"));
+    let mut cell = Cell::new(Value::from("cell"));
+    head.add(&mut cell);
+    let mut tail = Cell::new(Value::from("tail"));
+    cell.add(&mut tail);
+
+    assert_equal!(
+        head.iter().cloned().collect::<Vec<_>>(),
+        vec![Value::from("head"), Value::from("cell"), Value::from("tail")]
+    );
+    assert_equal!(
+        cell.iter().cloned().collect::<Vec<_>>(),
+        vec![Value::from("cell"), Value::from("tail")]
+    );
+}
+
+#[test]
+fn test_cell_into_iterator_ref() {
+    let cell = list!("a", "b", "c");
+    let mut values = Vec::new();
+    for value in &cell {
+        values.push(value.clone());
+    }
+    assert_equal!(values, cell.values());
+}
+
+#[test]
+fn test_cell_into_iterator() {
+    let cell = list!("a", 2u8, "c");
+    assert_equal!(
+        cell.into_iter().collect::<Vec<_>>(),
+        vec![Value::from("a"), Value::Byte(2), Value::from("c")]
+    );
+}
+
+#[test]
+fn test_cell_from_iterator_of_values() {
+    let values = vec![Value::from("head"), Value::from(7u64), Value::from(-1i64)];
+    let cell = values.clone().into_iter().collect::<Cell>();
+    assert_equal!(cell.len(), 3);
+    assert_equal!(cell.values(), values);
+    assert_equal!(cell.head(), Some(Value::from("head")));
+}
+
+#[test]
+fn test_cell_from_iterator_of_into_value() {
+    let cell = ["a", "b", "c"].into_iter().collect::<Cell>();
+    assert_equal!(cell.values(), vec![Value::from("a"), Value::from("b"), Value::from("c")]);
+
+    let cell = (1..=3u8).collect::<Cell>();
+    assert_equal!(cell.values(), vec![Value::Byte(1), Value::Byte(2), Value::Byte(3)]);
+
+    let cell = std::iter::empty::<Value>().collect::<Cell>();
+    assert_equal!(cell.is_nil(), true);
+}
+
+#[test]
+fn test_cell_extend() {
+    let mut cell = Cell::nil();
+    cell.extend(["a", "b"]);
+    assert_equal!(cell.values(), vec![Value::from("a"), Value::from("b")]);
+
+    cell.extend(vec![Value::from("c")]);
+    cell.extend(std::iter::empty::<Value>());
+    cell.extend([4u8]);
+    assert_equal!(
+        cell.values(),
+        vec![Value::from("a"), Value::from("b"), Value::from("c"), Value::Byte(4)]
+    );
+    assert_equal!(cell.len(), 4);
+}
+
+#[test]
+fn test_cell_extend_list() {
+    let mut cell = list!("head");
+    cell.extend(list!("middle", "tail"));
+    assert_equal!(
+        cell.values(),
+        vec![Value::from("head"), Value::from("middle"), Value::from("tail")]
+    );
+}
@@ -117,3 +117,46 @@ fn test_add_and_pop() {
     assert_equal!(head.values(), Vec::<Value>::new());
     assert_equal!(head.len(), 0);
 }
+#[test]
+fn test_set_end() {
+    let mut cell = Cell::new(Value::from("head"));
+    assert_equal!(cell.end(), None);
+
+    cell.set_end(Value::from("end"));
+    assert_equal!(cell.end(), Some(&Value::from("end")));
+    assert_equal!(cell.values(), vec![Value::from("head")]);
+    assert_equal!(cell.len(), 1);
+    assert_equal!(cell.clone(), cell);
+    assert_equal!(cell.clone().end(), Some(&Value::from("end")));
+    assert_equal!(cell == Cell::new(Value::from("head")), false);
+    assert_equal!(Cell::new(Value::from("head")) < cell, true);
+
+    cell.set_end(Value::List(Cell::new(Value::from("tail"))));
+    assert_equal!(cell.end(), None);
+    assert_equal!(cell.values(), vec![Value::from("head"), Value::from("tail")]);
+
+    cell.set_end(Value::Byte(1));
+    cell.set_end(Value::Nil);
+    assert_equal!(cell.end(), None);
+}
+#[test]
+fn test_add_replaces_end() {
+    let mut head = Cell::dotted(Value::from("head"), Value::from("end"));
+    let mut cell = Cell::dotted(Value::from("cell"), Value::from("tail"));
+
+    head.add(&mut cell);
+    assert_equal!(head.values(), vec![Value::from("head"), Value::from("cell")]);
+    assert_equal!(head.end(), Some(&Value::from("tail")));
+
+    head.extend([Value::from("more")]);
+    assert_equal!(head.end(), None);
+
+    let mut pair = Cell::dotted(Value::from("head"), Value::from("end"));
+    assert_equal!(pair.pop(), true);
+    assert_equal!(pair, Cell::new(Value::from("head")));
+}
+#[test]
+#[should_panic(expected = "cannot set the end of an empty list")]
+fn test_set_end_of_nil() {
+    Cell::nil().set_end(Value::from("end"));
+}
@@ -0,0 +1,95 @@
+#![allow(unused)]
+use ds::*;
+use k9::assert_equal;
+
+fn strings<'c>(cell: &Cell<'c>) -> Vec<String> {
+    cell.iter().map(|value| value.to_string()).collect()
+}
+
+fn build<'c>() -> Cell<'c> {
+    let mut head = Cell::new(Value::from(String::from("head")));
+    let mut middle = Cell::new(Value::from(String::from("middle")));
+    let mut tail = Cell::new(Value::from(String::from("tail")));
+    head.add(&mut middle);
+    middle.add(&mut tail);
+    head
+}
+
+#[test]
+fn test_cell_outlives_the_cells_it_was_built_from() {
+    let cell = build();
+    assert_equal!(strings(&cell), vec!["head", "middle", "tail"]);
+    assert_equal!(cell.len(), 3);
+}
+
+#[test]
+fn test_cell_add_shares_the_added_cell() {
+    let mut head = Cell::new(Value::from("head"));
+    let mut tail = Cell::new(Value::from("tail"));
+    head.add(&mut tail);
+    drop(head);
+    assert_equal!(strings(&tail), vec!["tail"]);
+
+    let mut head = Cell::new(Value::from("head"));
+    head.add(&mut tail);
+    tail.extend(["more"]);
+    assert_equal!(strings(&head), vec!["head", "tail", "more"]);
+}
+
+#[test]
+fn test_cell_share() {
+    let mut list = list!("a", "b");
+    let mut shared = list.share();
+    shared.extend(["c"]);
+    assert_equal!(strings(&list), vec!["a", "b", "c"]);
+    drop(list);
+    assert_equal!(strings(&shared), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_cell_cdr_shares_structure() {
+    let list = list!("a", "b", "c");
+    let mut rest = cdr(&list);
+    assert_equal!(strings(&rest), vec!["b", "c"]);
+    rest.extend(["d"]);
+    assert_equal!(strings(&list), vec!["a", "b", "c", "d"]);
+    drop(list);
+    assert_equal!(strings(&rest), vec!["b", "c", "d"]);
+    assert_equal!(strings(&cdr(&cdr(&cdr(&rest)))), Vec::<String>::new());
+}
+
+#[test]
+fn test_cell_cons_shares_tail() {
+    let mut tail = list!("b", "c");
+    let list = cons("a", &mut tail);
+    drop(tail);
+    assert_equal!(strings(&list), vec!["a", "b", "c"]);
+    assert_equal!(car(&list), Value::from("a"));
+}
+
+#[test]
+fn test_cell_clone_is_independent() {
+    let list = build();
+    let mut copy = list.clone();
+    copy.extend(["copy"]);
+    assert_equal!(strings(&list), vec!["head", "middle", "tail"]);
+    assert_equal!(strings(&copy), vec!["head", "middle", "tail", "copy"]);
+    drop(list);
+    assert_equal!(copy.len(), 4);
+}
+
+#[test]
+fn test_cell_pop_releases_tail() {
+    let mut list = list!("a", "b", "c");
+    let rest = cdr(&list);
+    assert_equal!(list.pop(), true);
+    assert_equal!(strings(&list), vec!["a"]);
+    assert_equal!(strings(&rest), vec!["b", "c"]);
+}
+
+#[test]
+fn test_cell_long_list_drop() {
+    let list = (0..100_000u64).collect::<Cell>();
+    assert_equal!(list.len(), 100_000);
+    drop(list);
+}
@@ -34,3 +34,147 @@ fn test_cdr() {
     let cell = list!("head", "middle", 33u8, "tail");
     assert_equal!(cdr(&cell), list!("middle", 33u8, "tail"));
 }
+
+fn strings<'c>(cell: &Cell<'c>) -> Vec<String> {
+    cell.iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn test_length() {
+    assert_equal!(cons::length(&list!(a, b, c)), 3);
+    assert_equal!(cons::length(&list!(a, [b, c])), 2);
+    assert_equal!(cons::length(&Cell::nil()), 0);
+}
+
+#[test]
+fn test_nth() {
+    let cell = list!(a, b, c);
+    assert_equal!(cons::nth(0, &cell), Value::from(symbol("a")));
+    assert_equal!(cons::nth(2, &cell), Value::from(symbol("c")));
+    assert_equal!(cons::nth(3, &cell), Value::Nil);
+    assert_equal!(cons::nth(0, &Cell::nil()), Value::Nil);
+}
+
+#[test]
+fn test_nthcdr() {
+    let mut cell = list!(a, b, c);
+    assert_equal!(cons::nthcdr(0, &mut cell), list!(a, b, c));
+    assert_equal!(cons::nthcdr(1, &mut cell), list!(b, c));
+    assert_equal!(cons::nthcdr(2, &mut cell), list!(c));
+    assert_equal!(cons::nthcdr(3, &mut cell).is_nil(), true);
+    assert_equal!(cons::nthcdr(10, &mut cell).is_nil(), true);
+    assert_equal!(cons::nthcdr(1, &mut Cell::nil()).is_nil(), true);
+}
+
+#[test]
+fn test_nthcdr_shares_structure() {
+    let mut cell = list!("a", "b", "c");
+    let mut rest = cons::nthcdr(2, &mut cell);
+    rest.extend(["d"]);
+    assert_equal!(strings(&cell), vec!["a", "b", "c", "d"]);
+
+    let mut same = cons::nthcdr(0, &mut cell);
+    same.extend(["e"]);
+    assert_equal!(strings(&cell), vec!["a", "b", "c", "d", "e"]);
+    drop(cell);
+    assert_equal!(strings(&rest), vec!["c", "d", "e"]);
+}
+
+#[test]
+fn test_nthcdr_skips_empty_cells() {
+    let mut cell = list!(1u8, 2u8);
+    cell.add(&mut Cell::nil());
+    cell.add(&mut list!(3u8, 4u8));
+    assert_equal!(cell.values(), list!(1u8, 2u8, 3u8, 4u8).values());
+
+    assert_equal!(cons::nth(2, &cell), Value::Byte(3));
+    assert_equal!(cons::nthcdr(2, &mut cell), list!(3u8, 4u8));
+    assert_equal!(cons::car(&cons::nthcdr(2, &mut cell)), Value::Byte(3));
+    assert_equal!(cons::nth(3, &cell), Value::Byte(4));
+    assert_equal!(cons::nthcdr(3, &mut cell), list!(4u8));
+    assert_equal!(cons::nthcdr(4, &mut cell).is_nil(), true);
+    assert_equal!(cons::last(&mut cell), list!(4u8));
+    assert_equal!(cons::member(&Value::Byte(4), &mut cell), list!(4u8));
+
+    let mut cell = Cell::nil();
+    cell.add(&mut Cell::nil());
+    cell.add(&mut list!(1u8, 2u8));
+    assert_equal!(cons::nthcdr(1, &mut cell), list!(2u8));
+}
+
+#[test]
+fn test_last() {
+    assert_equal!(cons::last(&mut list!(a, b, c)), list!(c));
+    assert_equal!(cons::last(&mut list!(a)), list!(a));
+    assert_equal!(cons::last(&mut Cell::nil()).is_nil(), true);
+
+    let mut cell = list!("a", "b");
+    let mut last = cons::last(&mut cell);
+    last.extend(["c"]);
+    assert_equal!(strings(&cell), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_butlast() {
+    let cell = list!(a, b, c);
+    let mut butlast = cons::butlast(&cell);
+    assert_equal!(butlast, list!(a, b));
+    butlast.extend([symbol("d")]);
+    assert_equal!(cell, list!(a, b, c));
+    assert_equal!(cons::butlast(&list!(a)).is_nil(), true);
+    assert_equal!(cons::butlast(&Cell::nil()).is_nil(), true);
+}
+
+#[test]
+fn test_member() {
+    let mut cell = list!(a, 1u8, [b], c);
+    assert_equal!(cons::member(&Value::Byte(1), &mut cell), list!(1u8, [b], c));
+    assert_equal!(cons::member(&Value::List(list!(b)), &mut cell), list!([b], c));
+    assert_equal!(cons::member(&Value::from(symbol("a")), &mut cell), cell);
+    assert_equal!(cons::member(&Value::UInt(1), &mut cell).is_nil(), true);
+    assert_equal!(cons::member(&Value::Nil, &mut Cell::nil()).is_nil(), true);
+}
+
+#[test]
+fn test_member_shares_structure() {
+    let mut cell = list!("a", "b", "c");
+    let mut rest = cons::member(&Value::from("b"), &mut cell);
+    rest.extend(["d"]);
+    assert_equal!(strings(&cell), vec!["a", "b", "c", "d"]);
+}
+
+#[test]
+fn test_append() {
+    let mut tail = list!(c, d);
+    assert_equal!(cons::append(&list!(a, b), &mut tail), list!(a, b, c, d));
+    assert_equal!(cons::append(&list!(a, b), &mut Cell::nil()), list!(a, b));
+    assert_equal!(cons::append(&Cell::nil(), &mut tail), list!(c, d));
+    assert_equal!(cons::append(&Cell::nil(), &mut Cell::nil()).is_nil(), true);
+}
+
+#[test]
+fn test_append_copies_list_and_shares_tail() {
+    let list = list!("a", "b");
+    let mut tail = list!("c");
+    let mut appended = cons::append(&list, &mut tail);
+
+    tail.extend(["d"]);
+    assert_equal!(strings(&appended), vec!["a", "b", "c", "d"]);
+
+    appended.extend(["e"]);
+    assert_equal!(strings(&list), vec!["a", "b"]);
+    assert_equal!(strings(&tail), vec!["c", "d", "e"]);
+
+    drop(tail);
+    assert_equal!(strings(&appended), vec!["a", "b", "c", "d", "e"]);
+}
+
+#[test]
+fn test_reverse() {
+    let cell = list!(a, [b, c], d);
+    assert_equal!(cons::reverse(&cell), list!(d, [b, c], a));
+    assert_equal!(cell, list!(a, [b, c], d));
+    assert_equal!(cons::reverse(&list!(a)), list!(a));
+    assert_equal!(cons::reverse(&Cell::nil()).is_nil(), true);
+    assert_equal!(cons::reverse(&cons::reverse(&cell)), cell);
+}
@@ -0,0 +1,179 @@
+#![allow(unused)]
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, RandomState};
+
+use ds::hash_table::HashTableStats;
+use ds::*;
+use k9::assert_equal;
+
+#[test]
+fn test_value_hash_consistent_with_eq() {
+    let state = RandomState::new();
+    let pairs = [
+        (Value::from("a"), Value::from(String::from("a"))),
+        (Value::from(symbol("a")), Value::from(symbol("a"))),
+        (Value::Float(f64::NAN), Value::Float(f64::NAN)),
+        (Value::List(list!(1u8, ["b"])), Value::List(list!(1u8, ["b"]))),
+    ];
+    for (a, b) in pairs {
+        assert_equal!(a, b);
+        assert_equal!(state.hash_one(&a), state.hash_one(&b));
+    }
+}
+
+#[test]
+fn test_value_and_cell_as_std_keys() {
+    let mut set = HashSet::new();
+    set.insert(Value::Byte(1));
+    set.insert(Value::UInt(1));
+    set.insert(Value::Float(0.0));
+    set.insert(Value::Float(-0.0));
+    set.insert(Value::Byte(1));
+    assert_equal!(set.len(), 4);
+
+    let mut map = HashMap::new();
+    map.insert(list!(a, b), 1);
+    map.insert(list!(a, [b]), 2);
+    assert_equal!(map.get(&list!(a, b)), Some(&1));
+    assert_equal!(map.get(&list!(a, [b])), Some(&2));
+    assert_equal!(map.get(&list!(a)), None);
+}
+
+#[test]
+fn test_hash_table_insert_get() {
+    let mut table = HashTable::new();
+    assert_equal!(table.is_empty(), true);
+    assert_equal!(table.insert("one", 1u8), None);
+    assert_equal!(table.insert(symbol("two"), 2u64), None);
+    assert_equal!(table.insert(list!(3u8), "three"), None);
+    assert_equal!(table.len(), 3);
+    assert_equal!(table.get(&Value::from("one")), Some(&Value::Byte(1)));
+    assert_equal!(table.get(&Value::from(symbol("two"))), Some(&Value::UInt(2)));
+    assert_equal!(table.get(&Value::List(list!(3u8))), Some(&Value::from("three")));
+    assert_equal!(table.get(&Value::from("two")), None);
+    assert_equal!(table.contains_key(&Value::from("one")), true);
+}
+
+#[test]
+fn test_hash_table_insert_replaces() {
+    let mut table = HashTable::new();
+    table.insert("key", "old");
+    assert_equal!(table.insert("key", "new"), Some(Value::from("old")));
+    assert_equal!(table.len(), 1);
+    assert_equal!(table.get(&Value::from("key")), Some(&Value::from("new")));
+
+    *table.get_mut(&Value::from("key")).unwrap() = Value::Bool(true);
+    assert_equal!(table.get(&Value::from("key")), Some(&Value::Bool(true)));
+}
+
+#[test]
+fn test_hash_table_remove() {
+    let mut table = (0..10u64).map(|n| (n, n * 10)).collect::<HashTable>();
+    assert_equal!(table.remove(&Value::UInt(3)), Some(Value::UInt(30)));
+    assert_equal!(table.remove(&Value::UInt(3)), None);
+    assert_equal!(table.len(), 9);
+    assert_equal!(table.stats().tombstones, 1);
+    for n in (0..10u64).filter(|n| *n != 3) {
+        assert_equal!(table.get(&Value::UInt(n)), Some(&Value::UInt(n * 10)));
+    }
+    table.insert(3u64, 33u64);
+    assert_equal!(table.get(&Value::UInt(3)), Some(&Value::UInt(33)));
+    assert_equal!(table.len(), 10);
+}
+
+#[test]
+fn test_hash_table_resizes() {
+    let mut table = HashTable::new();
+    assert_equal!(table.capacity(), 0);
+    table.insert(0u64, 0u64);
+    assert_equal!(table.capacity(), 8);
+    for n in 1..1000u64 {
+        table.insert(n, n);
+        assert_equal!(table.load_factor() <= HashTable::MAX_LOAD_FACTOR, true);
+    }
+    assert_equal!(table.len(), 1000);
+    assert_equal!(table.capacity(), 2048);
+    assert_equal!(table.capacity().is_power_of_two(), true);
+    for n in 0..1000u64 {
+        assert_equal!(table.get(&Value::UInt(n)), Some(&Value::UInt(n)));
+    }
+}
+
+#[test]
+fn test_hash_table_tombstones_are_reclaimed() {
+    let mut table = HashTable::with_capacity(6);
+    assert_equal!(table.capacity(), 8);
+    for round in 0..100u64 {
+        table.insert(round, round);
+        table.remove(&Value::UInt(round));
+    }
+    assert_equal!(table.len(), 0);
+    assert_equal!(table.capacity(), 8);
+    // inserting resizes once `len + tombstones` would exceed the load
+    // factor, which leaves up to 6 of the 8 slots deleted
+    let limit = (table.capacity() as f64 * HashTable::MAX_LOAD_FACTOR) as usize;
+    assert_equal!(table.stats().tombstones <= limit, true);
+}
+
+#[test]
+fn test_hash_table_stats() {
+    let table = HashTable::new();
+    assert_equal!(
+        table.stats(),
+        HashTableStats {
+            len: 0,
+            capacity: 0,
+            tombstones: 0,
+            load_factor: 0.0,
+            max_probe: 0,
+            average_probe: 0.0,
+        }
+    );
+
+    let table = (0..6u8).map(|n| (n, n)).collect::<HashTable>();
+    let stats = table.stats();
+    assert_equal!(stats.len, 6);
+    assert_equal!(stats.capacity, 8);
+    assert_equal!(stats.load_factor, 0.75);
+    assert_equal!(stats.max_probe < 6, true);
+    assert_equal!(stats.average_probe <= stats.max_probe as f64, true);
+}
+
+#[test]
+fn test_hash_table_iter() {
+    let table = [("a", 1u8), ("b", 2u8), ("c", 3u8)].into_iter().collect::<HashTable>();
+    let mut entries = table.iter().map(|(k, v)| (k.clone(), v.clone())).collect::<Vec<_>>();
+    entries.sort();
+    assert_equal!(
+        entries,
+        vec![
+            (Value::from("a"), Value::Byte(1)),
+            (Value::from("b"), Value::Byte(2)),
+            (Value::from("c"), Value::Byte(3)),
+        ]
+    );
+    let mut keys = table.keys().cloned().collect::<Vec<_>>();
+    keys.sort();
+    assert_equal!(keys, vec![Value::from("a"), Value::from("b"), Value::from("c")]);
+    assert_equal!(table.values().count(), 3);
+    assert_equal!((&table).into_iter().count(), 3);
+}
+
+#[test]
+fn test_hash_table_clone_and_clear() {
+    let mut table = [("a", list!(1u8, 2u8))].into_iter().collect::<HashTable>();
+    let copy = table.clone();
+    table.clear();
+    assert_equal!(table.len(), 0);
+    assert_equal!(table.get(&Value::from("a")), None);
+    assert_equal!(copy.get(&Value::from("a")), Some(&Value::List(list!(1u8, 2u8))));
+    assert_equal!(format!("{:?}", copy), "{\"a\": (1u8 2u8)}");
+}
+
+#[test]
+fn test_hash_table_lookup_with_borrowed_key() {
+    let mut table = HashTable::new();
+    table.insert(String::from("owned"), 1u8);
+    let text = String::from("owned");
+    assert_equal!(table.get(&Value::from(text.as_str())), Some(&Value::Byte(1)));
+}
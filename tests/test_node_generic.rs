@@ -0,0 +1,101 @@
+#![allow(unused)]
+use ds::*;
+use k9::assert_equal;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Task {
+    priority: u8,
+    name: String,
+}
+
+impl Task {
+    pub fn new(priority: u8, name: &str) -> Task {
+        Task {
+            priority,
+            name: name.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Task {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}:{}", self.priority, self.name)
+    }
+}
+
+pub fn tasks<'t>() -> BinaryTree<'t, Task> {
+    let mut tree = BinaryTree::empty();
+    for (priority, name) in [(3, "review"), (1, "deploy"), (2, "test"), (5, "plan"), (4, "build")] {
+        tree.insert(Task::new(priority, name));
+    }
+    tree
+}
+
+#[test]
+fn test_node_generic_new() {
+    let node = Node::new(Task::new(1, "deploy"));
+    assert_equal!(node.value(), Some(Task::new(1, "deploy")));
+    assert_equal!(node.item(), Task::new(1, "deploy"));
+    assert_equal!(node.is_nil(), false);
+
+    let node = Node::<Task>::empty();
+    assert_equal!(node.value(), None);
+    assert_equal!(node.item(), Task::default());
+    assert_equal!(node.is_nil(), true);
+}
+
+#[test]
+fn test_node_generic_links() {
+    let mut tree = BinaryTree::new(Task::new(2, "test"));
+    let node_a = tree.node(Task::new(1, "deploy"));
+    let node_c = tree.node(Task::new(3, "review"));
+    let root = tree.root_mut().unwrap();
+    root.set_left(node_a);
+    root.set_right(node_c);
+
+    assert_equal!(root.left_value(), Some(Task::new(1, "deploy")));
+    assert_equal!(root.right_value(), Some(Task::new(3, "review")));
+    assert_equal!(node_a.parent_value(), Some(Task::new(2, "test")));
+    assert_equal!(root.successor().value(), Some(Task::new(3, "review")));
+    assert_equal!(tree.len(), 3);
+}
+
+#[test]
+fn test_node_generic_set_interface() {
+    let mut tree = tasks();
+    assert_equal!(tree.len(), 5);
+    assert_equal!(tree.root().unwrap().is_avl(), true);
+    assert_equal!(tree.find_min().unwrap().item(), Task::new(1, "deploy"));
+    assert_equal!(tree.find_max().unwrap().item(), Task::new(5, "plan"));
+    assert_equal!(
+        tree.find_next(&Task::new(3, "review")).unwrap().item(),
+        Task::new(4, "build")
+    );
+    assert_equal!(tree.delete(&Task::new(3, "review")), Some(Task::new(3, "review")));
+    assert_equal!(tree.find(&Task::new(3, "review")).is_none(), true);
+    assert_equal!(
+        tree.iter_inorder().values().map(|task| task.name).collect::<Vec<_>>(),
+        vec!["deploy", "test", "build", "plan"]
+    );
+}
+
+#[test]
+fn test_node_generic_sequence_interface() {
+    let mut tree = BinaryTree::<Task>::empty();
+    tree.insert_at(0, Task::new(9, "last"));
+    tree.insert_at(0, Task::new(9, "first"));
+    tree.insert_at(1, Task::new(0, "middle"));
+    assert_equal!(
+        tree.iter_inorder().values().map(|task| task.to_string()).collect::<Vec<_>>(),
+        vec!["9:first", "0:middle", "9:last"]
+    );
+    assert_equal!(tree.delete_at(1), Some(Task::new(0, "middle")));
+    assert_equal!(tree.get_at(1), Some(Task::new(9, "last")));
+}
+
+#[test]
+fn test_node_generic_default_payload_is_value() {
+    let node: Node = Node::new(Value::from("A"));
+    let tree: BinaryTree = BinaryTree::new(Value::from("A"));
+    assert_equal!(node.value(), tree.root().unwrap().value());
+}
@@ -0,0 +1,150 @@
+#![allow(unused)]
+use std::borrow::Cow;
+
+use ds::*;
+use k9::assert_equal;
+
+fn parse(text: &str) -> OwnedCell {
+    sexp::read(text).unwrap().into_owned()
+}
+
+fn first_word(text: &str) -> OwnedValue {
+    Value::from(text.split_whitespace().next().unwrap()).into_owned()
+}
+
+struct Config {
+    forms: OwnedCell,
+    name: OwnedValue,
+}
+
+#[test]
+fn test_value_into_owned_copies_borrowed_strings() {
+    let text = String::from("borrowed");
+    let value = Value::from(text.as_str());
+    assert_equal!(matches!(value, Value::String(Cow::Borrowed(_))), true);
+
+    let owned = value.into_owned();
+    drop(text);
+    assert_equal!(matches!(owned, Value::String(Cow::Owned(_))), true);
+    assert_equal!(owned, Value::from("borrowed"));
+}
+
+#[test]
+fn test_value_into_owned_keeps_other_variants() {
+    for value in [
+        Value::Nil,
+        Value::from(symbol("a")),
+        Value::Byte(1),
+        Value::UInt(2),
+        Value::Int(-3),
+        Value::Float(4.5),
+        Value::Bool(true),
+        Value::Char('x'),
+    ] {
+        let kind = value.kind();
+        let owned = value.clone().into_owned();
+        assert_equal!(owned.kind(), kind);
+        assert_equal!(owned, value);
+    }
+}
+
+#[test]
+fn test_value_into_owned_nested_list() {
+    let text = String::from("(a \"b\" (\"c\" 1u8))");
+    let value = Value::List(sexp::read(&text).unwrap());
+    let owned: OwnedValue = value.into_owned();
+    drop(text);
+    assert_equal!(owned.to_string(), "(a \"b\" (\"c\" 1u8))");
+}
+
+#[test]
+fn test_value_into_owned_from_function() {
+    assert_equal!(first_word("hello world"), Value::from("hello"));
+}
+
+#[test]
+fn test_cell_into_owned() {
+    let forms = parse("(define \"x\" (\"y\" 2u8))");
+    assert_equal!(forms.len(), 3);
+    assert_equal!(forms.to_string(), "(define \"x\" (\"y\" 2u8))");
+    for value in forms.iter() {
+        if let Value::String(string) = value {
+            assert_equal!(matches!(string, Cow::Owned(_)), true);
+        }
+    }
+}
+
+#[test]
+fn test_cell_into_owned_empty() {
+    assert_equal!(Cell::nil().into_owned().is_nil(), true);
+}
+
+#[test]
+fn test_cell_into_owned_does_not_share() {
+    let mut list = list!("a", "b");
+    let mut shared = list.share();
+    let owned = shared.into_owned();
+    list.pop();
+    assert_equal!(list, list!("a"));
+    assert_equal!(owned, list!("a", "b"));
+}
+
+#[test]
+fn test_owned_aliases_in_structs() {
+    let config = {
+        let text = String::from("(app (debug true))");
+        let forms = parse(&text);
+        let name = forms.head().unwrap().into_owned();
+        Config { forms, name }
+    };
+    assert_equal!(config.name, Value::from(symbol("app")));
+    assert_equal!(config.forms.to_string(), "(app (debug true))");
+}
+
+#[test]
+fn test_binary_tree_into_owned() {
+    let text = String::from("A B C D");
+    let words = text.split(' ').collect::<Vec<_>>();
+    let tree: OwnedBinaryTree = {
+        let mut tree = BinaryTree::new(Value::from(words[0]));
+        let node_b = tree.node(Value::from(words[1]));
+        let node_c = tree.node(Value::from(words[2]));
+        let node_d = tree.node(Value::from(words[3]));
+        node_b.set_left(node_d);
+        let root = tree.root_mut().unwrap();
+        root.set_left(node_b);
+        root.set_right(node_c);
+        tree.into_owned()
+    };
+    drop(words);
+    drop(text);
+
+    assert_equal!(tree.len(), 4);
+    let root = tree.root().unwrap();
+    assert_equal!(root.value(), Some(Value::from("A")));
+    assert_equal!(root.left_value(), Some(Value::from("B")));
+    assert_equal!(root.right_value(), Some(Value::from("C")));
+    assert_equal!(root.left().unwrap().left_value(), Some(Value::from("D")));
+    assert_equal!(root.left().unwrap().left().unwrap().parent_value(), Some(Value::from("B")));
+}
+
+#[test]
+fn test_binary_tree_into_owned_empty() {
+    assert_equal!(BinaryTree::nil().into_owned().is_empty(), true);
+}
+
+#[test]
+fn test_node_owned_subtree() {
+    let mut tree = BinaryTree::new(Value::from("A"));
+    let node_b = tree.node(Value::from("B"));
+    let node_c = tree.node(Value::from("C"));
+    node_b.set_right(node_c);
+    tree.root_mut().unwrap().set_left(node_b);
+
+    let subtree = tree.root().unwrap().left().unwrap().owned_subtree();
+    drop(tree);
+    assert_equal!(subtree.len(), 2);
+    assert_equal!(subtree.root().unwrap().value(), Some(Value::from("B")));
+    assert_equal!(subtree.root().unwrap().right_value(), Some(Value::from("C")));
+    assert_equal!(subtree.root().unwrap().parent(), None);
+}
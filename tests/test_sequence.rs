@@ -0,0 +1,201 @@
+#![allow(unused)]
+use ds::*;
+use k9::assert_equal;
+
+fn sequence<'t>(values: &[&'t str]) -> BinaryTree<'t> {
+    let mut tree = BinaryTree::nil();
+    for (index, value) in values.iter().enumerate() {
+        tree.insert_at(index, Value::from(*value));
+    }
+    tree
+}
+
+fn items<'t>(tree: &BinaryTree<'t>) -> Vec<Value<'t>> {
+    (0..tree.len()).filter_map(|index| tree.get_at(index)).collect()
+}
+
+fn expected<'t>(values: &[&'t str]) -> Vec<Value<'t>> {
+    values.iter().map(|value| Value::from(*value)).collect()
+}
+
+#[test]
+fn test_sequence_size_after_set_left_and_set_right() {
+    ///|||||||||||||||||||||||||||||||||||||||||||||\\\
+    ///                                             \\\
+    ///                     A                       \\\
+    ///                    / \                      \\\
+    ///                   B   C                     \\\
+    ///                  /                          \\\
+    ///                 D                           \\\
+    ///                                             \\\
+    let mut tree = BinaryTree::new(Value::from("A"));
+    let node_b = tree.node(Value::from("B"));
+    let node_c = tree.node(Value::from("C"));
+    let node_d = tree.node(Value::from("D"));
+    let root = tree.root_mut().unwrap();
+    assert_equal!(root.size(), 1);
+    root.set_left(node_b);
+    root.set_right(node_c);
+    assert_equal!(root.size(), 3);
+    node_b.set_left(node_d);
+    assert_equal!(root.size(), 4);
+    assert_equal!(node_b.size(), 2);
+    assert_equal!(node_d.size(), 1);
+    assert_equal!(tree.len(), 4);
+
+    assert_equal!(tree.get_at(0), Some(Value::from("D")));
+    assert_equal!(tree.get_at(1), Some(Value::from("B")));
+    assert_equal!(tree.get_at(2), Some(Value::from("A")));
+    assert_equal!(tree.get_at(3), Some(Value::from("C")));
+    assert_equal!(tree.get_at(4), None);
+}
+
+#[test]
+fn test_sequence_size_after_swap_item_and_subtree_delete() {
+    let mut tree = sequence(&["A", "B", "C"]);
+    let root = tree.root_mut().unwrap();
+    let first = root.subtree_at_mut(0).unwrap();
+    let last = root.subtree_at_mut(2).unwrap();
+    first.swap_item(last);
+    assert_equal!(root.size(), 3);
+    assert_equal!(items(&tree), expected(&["C", "B", "A"]));
+
+    let node = tree.root_mut().unwrap().subtree_at_mut(0).unwrap();
+    tree.subtree_delete(node);
+    assert_equal!(tree.len(), 2);
+    assert_equal!(tree.root().unwrap().is_avl(), true);
+    assert_equal!(items(&tree), expected(&["B", "A"]));
+}
+
+#[test]
+fn test_sequence_insert_at_end() {
+    let tree = sequence(&["a", "b", "c", "d", "e", "f", "g", "h"]);
+    assert_equal!(tree.len(), 8);
+    assert_equal!(tree.root().unwrap().is_avl(), true);
+    assert_equal!(items(&tree), expected(&["a", "b", "c", "d", "e", "f", "g", "h"]));
+}
+
+#[test]
+fn test_sequence_insert_at_front_and_middle() {
+    let mut tree = sequence(&["c", "e"]);
+    tree.insert_at(0, Value::from("a"));
+    tree.insert_at(1, Value::from("b"));
+    tree.insert_at(3, Value::from("d"));
+    let node = tree.insert_at(5, Value::from("f"));
+    assert_equal!(node.value(), Some(Value::from("f")));
+    assert_equal!(tree.root().unwrap().is_avl(), true);
+    assert_equal!(items(&tree), expected(&["a", "b", "c", "d", "e", "f"]));
+}
+
+#[test]
+fn test_sequence_insert_at_keeps_duplicates() {
+    let tree = sequence(&["x", "x", "x"]);
+    assert_equal!(tree.len(), 3);
+    assert_equal!(items(&tree), expected(&["x", "x", "x"]));
+}
+
+#[test]
+#[should_panic]
+fn test_sequence_insert_at_out_of_bounds() {
+    let mut tree = sequence(&["a"]);
+    tree.insert_at(2, Value::from("c"));
+}
+
+#[test]
+fn test_sequence_set_at() {
+    let mut tree = sequence(&["a", "b", "c"]);
+    assert_equal!(tree.set_at(1, Value::from("B")), Some(Value::from("b")));
+    assert_equal!(tree.set_at(3, Value::from("D")), None);
+    assert_equal!(items(&tree), expected(&["a", "B", "c"]));
+}
+
+#[test]
+fn test_sequence_delete_at() {
+    let mut tree = sequence(&["a", "b", "c", "d", "e", "f", "g"]);
+    assert_equal!(tree.delete_at(3), Some(Value::from("d")));
+    assert_equal!(tree.delete_at(0), Some(Value::from("a")));
+    assert_equal!(tree.delete_at(4), Some(Value::from("g")));
+    assert_equal!(tree.delete_at(4), None);
+    assert_equal!(tree.root().unwrap().is_avl(), true);
+    assert_equal!(items(&tree), expected(&["b", "c", "e", "f"]));
+
+    while !tree.is_empty() {
+        tree.delete_at(0);
+    }
+    assert_equal!(tree.len(), 0);
+    assert_equal!(tree.get_at(0), None);
+}
+
+#[test]
+fn test_sequence_index_of() {
+    let mut tree = BinaryTree::nil();
+    for index in 0..50u64 {
+        tree.insert_at(index as usize, Value::from(index));
+    }
+    for index in 0..50 {
+        let node = tree.subtree_at(index).unwrap();
+        assert_equal!(tree.index_of(node), index);
+        assert_equal!(node.value(), Some(Value::from(index as u64)));
+    }
+}
+
+#[test]
+fn test_sequence_insert_at_from_child_rotating_the_root() {
+    ///|||||||||||||||||||||||||||||||||||||||||||||\\\
+    ///                                             \\\
+    ///            D                    B           \\\
+    ///           / \                  / \          \\\
+    ///          B   E      =>        A   D         \\\
+    ///         / \                  /   / \        \\\
+    ///        A   C                0   C   E       \\\
+    ///                                             \\\
+    let mut tree = BinaryTree::new(Value::from("D"));
+    let node_a = tree.node(Value::from("A"));
+    let node_b = tree.node(Value::from("B"));
+    let node_c = tree.node(Value::from("C"));
+    let node_e = tree.node(Value::from("E"));
+    let root = tree.root_mut().unwrap();
+    root.set_left(node_b);
+    root.set_right(node_e);
+    node_b.set_left(node_a);
+    node_b.set_right(node_c);
+
+    let node_0 = node_b.insert_at(0, Value::from("0"));
+
+    assert_equal!(node_0.value(), Some(Value::from("0")));
+    assert_equal!(node_0.index_of(), 0);
+    assert_equal!(tree.root().unwrap().value(), Some(Value::from("B")));
+    assert_equal!(tree.root().unwrap().is_avl(), true);
+    assert_equal!(items(&tree), expected(&["0", "A", "B", "C", "D", "E"]));
+}
+
+#[test]
+fn test_sequence_insert_at_and_delete_at_on_empty_node() {
+    let mut root = Node::nil();
+    assert_equal!(root.size(), 0);
+    assert_equal!(root.get_at(0), None);
+
+    let node = root.insert_at(0, Value::from(5u8));
+    assert_equal!(node.addr(), root.addr());
+    assert_equal!(root.size(), 1);
+    assert_equal!(root.leaf(), true);
+    assert_equal!(root.get_at(0), Some(Value::from(5u8)));
+    assert_equal!(root.get_at(1), None);
+
+    root.insert_at(1, Value::from(6u8));
+    assert_equal!(root.size(), 2);
+    assert_equal!(root.get_at(1), Some(Value::from(6u8)));
+
+    assert_equal!(root.delete_at(0), Some(Value::from(5u8)));
+    assert_equal!(root.size(), 1);
+    assert_equal!(root.get_at(0), Some(Value::from(6u8)));
+    assert_equal!(root.delete_at(0), Some(Value::from(6u8)));
+    assert_equal!(root.size(), 0);
+    assert_equal!(root.value(), None);
+    assert_equal!(root.get_at(0), None);
+
+    root.insert(Value::from(7u8));
+    assert_equal!(root.size(), 1);
+    assert_equal!(root.delete(&Value::from(7u8)), Some(Value::from(7u8)));
+    assert_equal!(root.size(), 0);
+}
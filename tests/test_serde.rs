@@ -0,0 +1,229 @@
+#![allow(unused)]
+use ds::serialization::{tagged, Tagged};
+use ds::*;
+use k9::assert_equal;
+use serde::{Deserialize, Serialize};
+
+fn round_trip<T: Serialize + Deserialize<'static>>(value: &T) -> T {
+    let json = serde_json::to_string(value).unwrap();
+    serde_json::from_str(Box::leak(json.into_boxed_str())).unwrap()
+}
+
+pub fn tree<'t>() -> BinaryTree<'t, u64> {
+    ///|||||||||||||||||||||||||||||||||||||||||||||\\\
+    ///                                             \\\
+    ///                     4                       \\\
+    ///                    / \                      \\\
+    ///                   2   5                     \\\
+    ///                  / \                        \\\
+    ///                 1   3                       \\\
+    ///                                             \\\
+    let mut tree = BinaryTree::new(4);
+    let node_2 = tree.node(2);
+    let node_5 = tree.node(5);
+    let node_1 = tree.node(1);
+    let node_3 = tree.node(3);
+
+    node_2.set_left(node_1);
+    node_2.set_right(node_3);
+
+    let root = tree.root_mut().unwrap();
+    root.set_left(node_2);
+    root.set_right(node_5);
+    tree
+}
+
+#[test]
+fn test_serde_value_untagged() {
+    assert_equal!(serde_json::to_string(&Value::Nil).unwrap(), "null");
+    assert_equal!(serde_json::to_string(&Value::from("a")).unwrap(), r#""a""#);
+    assert_equal!(serde_json::to_string(&Value::from(symbol("a"))).unwrap(), r#""a""#);
+    assert_equal!(serde_json::to_string(&Value::Byte(7)).unwrap(), "7");
+    assert_equal!(serde_json::to_string(&Value::UInt(7)).unwrap(), "7");
+    assert_equal!(serde_json::to_string(&Value::Int(-7)).unwrap(), "-7");
+    assert_equal!(serde_json::to_string(&Value::Float(1.5)).unwrap(), "1.5");
+    assert_equal!(serde_json::to_string(&Value::Bool(true)).unwrap(), "true");
+    assert_equal!(serde_json::to_string(&Value::Char('x')).unwrap(), r#""x""#);
+    assert_equal!(serde_json::to_string(&Value::List(list!(1u8, ["a"]))).unwrap(), r#"[1,["a"]]"#);
+}
+
+#[test]
+fn test_serde_value_untagged_round_trip() {
+    for value in [
+        Value::Nil,
+        Value::from("a"),
+        Value::UInt(7),
+        Value::Int(-7),
+        Value::Float(1.5),
+        Value::Bool(false),
+        Value::List(list!("a", 1000u64, ["b", true])),
+    ] {
+        assert_equal!(round_trip(&value), value);
+    }
+}
+
+#[test]
+fn test_serde_value_untagged_is_lossy() {
+    assert_equal!(round_trip(&Value::Byte(7)), Value::UInt(7));
+    assert_equal!(round_trip(&Value::Int(7)), Value::UInt(7));
+    assert_equal!(round_trip(&Value::from(symbol("a"))), Value::from("a"));
+    assert_equal!(round_trip(&Value::Char('x')), Value::from("x"));
+}
+
+#[test]
+fn test_serde_value_tagged() {
+    assert_equal!(serde_json::to_string(&Tagged(Value::Nil)).unwrap(), r#""Nil""#);
+    assert_equal!(serde_json::to_string(&Tagged(Value::Byte(7))).unwrap(), r#"{"Byte":7}"#);
+    assert_equal!(
+        serde_json::to_string(&Tagged(Value::from(symbol("a")))).unwrap(),
+        r#"{"Symbol":"a"}"#
+    );
+    assert_equal!(
+        serde_json::to_string(&Tagged(Value::List(list!(1u8, [a])))).unwrap(),
+        r#"{"List":[{"Byte":1},{"List":[{"Symbol":"a"}]}]}"#
+    );
+}
+
+#[test]
+fn test_serde_value_tagged_round_trip() {
+    for value in [
+        Value::Nil,
+        Value::from("a"),
+        Value::from(symbol("a")),
+        Value::Byte(7),
+        Value::UInt(7),
+        Value::Int(7),
+        Value::Float(-0.5),
+        Value::Bool(true),
+        Value::Char('x'),
+        Value::List(list!(1u8, 1u64, 1i64, [a, "a", 'a'])),
+        Value::List(Cell::nil()),
+    ] {
+        let tagged = round_trip(&Tagged(value.clone()));
+        assert_equal!(tagged.0.kind(), value.kind());
+        assert_equal!(tagged.0, value);
+    }
+}
+
+#[test]
+fn test_serde_value_tagged_field() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Entry<'c> {
+        #[serde(with = "tagged")]
+        key: Value<'c>,
+        value: Value<'c>,
+    }
+
+    let entry = Entry {
+        key: Value::Byte(1),
+        value: Value::from("one"),
+    };
+    let json = serde_json::to_string(&entry).unwrap();
+    assert_equal!(json, r#"{"key":{"Byte":1},"value":"one"}"#);
+    assert_equal!(serde_json::from_str::<Entry>(&json).unwrap(), entry);
+}
+
+#[test]
+fn test_serde_value_tagged_unknown_variant() {
+    let error = serde_json::from_str::<Tagged>(r#"{"Word":"a"}"#).unwrap_err();
+    assert_equal!(error.to_string().starts_with("unknown variant `Word`"), true);
+}
+
+#[test]
+fn test_serde_cell() {
+    let cell = list!("a", 1000u64, ["b", [2000u64]], true);
+    let json = serde_json::to_string(&cell).unwrap();
+    assert_equal!(json, r#"["a",1000,["b",[2000]],true]"#);
+    assert_equal!(serde_json::from_str::<Cell>(&json).unwrap(), cell);
+    assert_equal!(serde_json::to_string(&Cell::nil()).unwrap(), "[]");
+    assert_equal!(serde_json::from_str::<Cell>("[]").unwrap(), Cell::nil());
+}
+
+#[test]
+fn test_serde_cell_rejects_improper_lists() {
+    let pair = Cell::dotted(Value::from("a"), Value::from("b"));
+    let error = serde_json::to_string(&pair).unwrap_err();
+    assert_equal!(error.to_string(), "improper lists cannot be serialized");
+}
+
+#[test]
+fn test_serde_cell_rejects_scalars() {
+    assert_equal!(serde_json::from_str::<Cell>("1").is_err(), true);
+}
+
+#[test]
+fn test_serde_cell_rejects_non_sequences() {
+    use serde::de::value::{Error, StrDeserializer, U64Deserializer};
+    let error = Cell::deserialize(StrDeserializer::<Error>::new("x")).unwrap_err();
+    assert_equal!(error.to_string(), r#"invalid type: string "x", expected a sequence"#);
+    let error = Cell::deserialize(U64Deserializer::<Error>::new(1000)).unwrap_err();
+    assert_equal!(error.to_string(), "invalid type: integer `1000`, expected a sequence");
+}
+
+#[test]
+fn test_serde_cell_long_sequence() {
+    let json = serde_json::to_string(&(0..10_000u64).collect::<Vec<_>>()).unwrap();
+    let cell = serde_json::from_str::<Cell>(&json).unwrap();
+    assert_equal!(cell.len(), 10_000);
+    assert_equal!(cell, (0..10_000u64).collect::<Cell>());
+    assert_equal!(
+        serde_json::from_str::<Value>(&json).unwrap(),
+        Value::List((0..10_000u64).collect())
+    );
+}
+
+#[test]
+fn test_serde_node() {
+    let tree = tree();
+    let node = tree.root().unwrap().left().unwrap();
+    assert_equal!(
+        serde_json::to_string(node).unwrap(),
+        r#"{"value":2,"left":{"value":1,"left":null,"right":null},"right":{"value":3,"left":null,"right":null}}"#
+    );
+}
+
+#[test]
+fn test_serde_binary_tree_round_trip() {
+    let tree = tree();
+    let json = serde_json::to_string(&tree).unwrap();
+    let copy: BinaryTree<u64> = serde_json::from_str(&json).unwrap();
+
+    assert_equal!(copy.len(), 5);
+    assert_equal!(
+        copy.iter_preorder().map(|node| node.item()).collect::<Vec<_>>(),
+        vec![4, 2, 1, 3, 5]
+    );
+    assert_equal!(copy.root().unwrap().left().unwrap().right().unwrap().parent_value(), Some(2));
+    assert_equal!(serde_json::to_string(&copy).unwrap(), json);
+}
+
+#[test]
+fn test_serde_binary_tree_empty() {
+    let tree = BinaryTree::<u64>::empty();
+    assert_equal!(serde_json::to_string(&tree).unwrap(), "null");
+    assert_equal!(serde_json::from_str::<BinaryTree<u64>>("null").unwrap().is_empty(), true);
+}
+
+#[test]
+fn test_serde_binary_tree_of_values() {
+    let tree = BinaryTree::new(Value::from("A"));
+    let json = serde_json::to_string(&tree).unwrap();
+    assert_equal!(json, r#"{"value":"A","left":null,"right":null}"#);
+    let copy: BinaryTree = serde_json::from_str(&json).unwrap();
+    assert_equal!(copy.root().unwrap().value(), Some(Value::from("A")));
+}
+
+#[test]
+fn test_serde_binary_tree_missing_children() {
+    let copy: BinaryTree<u64> = serde_json::from_str(r#"{"value":1,"right":{"value":2}}"#).unwrap();
+    assert_equal!(copy.len(), 2);
+    assert_equal!(copy.root().unwrap().left(), None);
+    assert_equal!(copy.root().unwrap().right_value(), Some(2));
+}
+
+#[test]
+fn test_serde_binary_tree_missing_value() {
+    let error =
+        serde_json::from_str::<BinaryTree<u64>>(r#"{"value":1,"left":{"left":null}}"#).unwrap_err();
+    assert_equal!(error.to_string().starts_with("missing field `value`"), true);
+}
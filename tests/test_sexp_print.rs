@@ -0,0 +1,90 @@
+#![allow(unused)]
+use ds::sexp::{pretty, read};
+use ds::*;
+use k9::assert_equal;
+
+#[test]
+fn test_display_list() {
+    let cell = list!("head", "middle", 33u8, "tail");
+    assert_equal!(cell.to_string(), r#"("head" "middle" 33u8 "tail")"#);
+}
+
+#[test]
+fn test_display_atoms() {
+    let cell = [
+        Value::Byte(7),
+        Value::UInt(7),
+        Value::Int(-7),
+        Value::Nil,
+        Value::from("say \"hi\"\n"),
+    ]
+    .into_iter()
+    .collect::<Cell>();
+    assert_equal!(cell.to_string(), r#"(7u8 7u64 -7i64 nil "say \"hi\"\n")"#);
+}
+
+#[test]
+fn test_display_nil() {
+    assert_equal!(Cell::nil().to_string(), "()");
+    assert_equal!(format!("{:#}", Cell::nil()), "()");
+}
+
+fn atoms<'c>() -> Cell<'c> {
+    [
+        Value::from("head"),
+        Value::Byte(255),
+        Value::UInt(u64::MAX),
+        Value::Int(i64::MIN),
+        Value::Nil,
+        Value::from("tab\tquote\"\u{1F600}"),
+        Value::from(""),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn test_display_round_trips_through_read() {
+    let text = atoms().to_string();
+    let alternate = format!("{:#}", atoms());
+    let narrow = pretty(&atoms(), 0);
+    assert_equal!(read(&text).unwrap(), atoms());
+    assert_equal!(read(&alternate).unwrap(), atoms());
+    assert_equal!(read(&narrow).unwrap(), atoms());
+    assert_equal!(narrow.lines().count(), 7);
+}
+
+#[test]
+fn test_display_pretty_fits() {
+    let cell = list!("a", "b", "c");
+    assert_equal!(format!("{:#}", cell), cell.to_string());
+    assert_equal!(pretty(&cell, 13), r#"("a" "b" "c")"#);
+}
+
+#[test]
+fn test_display_pretty_breaks_long_lists() {
+    let cell = list!("a", "b", "c");
+    assert_equal!(pretty(&cell, 12), "(\"a\"\n \"b\"\n \"c\")");
+
+    let cell = (0..30u64).collect::<Cell>();
+    let text = format!("{:#}", cell);
+    assert_equal!(text.lines().count(), 30);
+    assert_equal!(text.lines().nth(1), Some(" 1u64"));
+    assert_equal!(text.lines().all(|line| line.len() <= sexp::PRETTY_WIDTH), true);
+}
+
+#[test]
+fn test_display_dotted_pairs() {
+    let pair = Cell::dotted(Value::from("a"), Value::Byte(1));
+    assert_equal!(pair.to_string(), r#"("a" . 1u8)"#);
+    let mut cell = list!(x, y);
+    cell.set_end(Value::from(symbol("z")));
+    assert_equal!(cell.to_string(), "(x y . z)");
+    assert_equal!(pretty(&cell, 0), "(x\n y\n . z)");
+    assert_equal!(read(&pretty(&cell, 0)).unwrap(), cell);
+
+    let nested = [Value::List(Cell::nil()), Value::List(pair)].into_iter().collect::<Cell>();
+    assert_equal!(nested.to_string(), r#"(() ("a" . 1u8))"#);
+    assert_equal!(read(&nested.to_string()).unwrap(), nested);
+    assert_equal!(read(&format!("{:#}", nested)).unwrap(), nested);
+}
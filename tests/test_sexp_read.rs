@@ -0,0 +1,179 @@
+#![allow(unused)]
+use ds::sexp::{read, ParseError, ParseErrorKind};
+use ds::*;
+use k9::assert_equal;
+
+fn error(kind: ParseErrorKind, line: usize, column: usize) -> ParseError {
+    ParseError { kind, line, column }
+}
+
+#[test]
+fn test_read_list() {
+    let cell = read("(head \"middle\" 33u8 tail)").unwrap();
+    assert_equal!(
+        cell.values(),
+        vec![
+            Value::from(symbol("head")),
+            Value::from("middle"),
+            Value::Byte(33),
+            Value::from(symbol("tail")),
+        ]
+    );
+    assert_equal!(cell, list!(head, "middle", 33u8, tail));
+}
+
+#[test]
+fn test_read_integers() {
+    let cell = read("(255u8 18446744073709551615u64 -9223372036854775808i64 42 -42 0)").unwrap();
+    assert_equal!(
+        cell.values(),
+        vec![
+            Value::Byte(255),
+            Value::UInt(u64::MAX),
+            Value::Int(i64::MIN),
+            Value::UInt(42),
+            Value::Int(-42),
+            Value::UInt(0),
+        ]
+    );
+}
+
+#[test]
+fn test_read_integers_matching_debug() {
+    for value in [Value::Byte(7), Value::UInt(7), Value::Int(-7)] {
+        let text = format!("{:?}", value);
+        assert_equal!(read(&text).unwrap().head().unwrap(), value);
+    }
+}
+
+#[test]
+fn test_read_strings() {
+    let cell = read(r#"("plain" "with \"quotes\"" "tab\tnew\nline" "\u{1F600}" "")"#).unwrap();
+    assert_equal!(
+        cell.values(),
+        vec![
+            Value::from("plain"),
+            Value::from("with \"quotes\""),
+            Value::from("tab\tnew\nline"),
+            Value::from("\u{1F600}"),
+            Value::from(""),
+        ]
+    );
+}
+
+#[test]
+fn test_read_strings_matching_debug() {
+    let value = Value::from("say \"hi\"\n\t\\");
+    let text = format!("{:?}", value);
+    assert_equal!(read(&text).unwrap().head().unwrap(), value);
+}
+
+#[test]
+fn test_read_nil() {
+    assert_equal!(read("nil").unwrap().is_nil(), true);
+    assert_equal!(read("()").unwrap().is_nil(), true);
+    assert_equal!(read("  ( )  ").unwrap().is_nil(), true);
+    assert_equal!(
+        read("(a nil b)").unwrap().values(),
+        vec![Value::from(symbol("a")), Value::Nil, Value::from(symbol("b"))]
+    );
+}
+
+#[test]
+fn test_read_atom() {
+    assert_equal!(read("atom").unwrap().values(), vec![Value::from(symbol("atom"))]);
+    assert_equal!(read(" 33u8 ").unwrap().values(), vec![Value::Byte(33)]);
+    assert_equal!(read("-").unwrap().values(), vec![Value::from(symbol("-"))]);
+}
+
+#[test]
+fn test_read_quote() {
+    assert_equal!(
+        read("'atom").unwrap().values(),
+        vec![Value::from(symbol("quote")), Value::from(symbol("atom"))]
+    );
+}
+
+#[test]
+fn test_read_comments_and_whitespace() {
+    let cell = read("; leading comment\n(a ; inline comment\n\t b)\n; trailing").unwrap();
+    assert_equal!(cell.values(), vec![Value::from(symbol("a")), Value::from(symbol("b"))]);
+}
+
+#[test]
+fn test_read_errors() {
+    assert_equal!(read(""), Err(error(ParseErrorKind::UnexpectedEof, 1, 1)));
+    assert_equal!(read("  ; nothing\n"), Err(error(ParseErrorKind::UnexpectedEof, 2, 1)));
+    assert_equal!(read("(a b"), Err(error(ParseErrorKind::UnclosedList, 1, 1)));
+    assert_equal!(read(")"), Err(error(ParseErrorKind::UnexpectedClose, 1, 1)));
+    assert_equal!(read("(a) b"), Err(error(ParseErrorKind::TrailingInput('b'), 1, 5)));
+    assert_equal!(read("(a\n  \"b)"), Err(error(ParseErrorKind::UnterminatedString, 2, 3)));
+    assert_equal!(
+        read("(a\n  256u8)"),
+        Err(error(ParseErrorKind::InvalidNumber("256u8".to_string()), 2, 3))
+    );
+    assert_equal!(read("(1x)"), Err(error(ParseErrorKind::InvalidNumber("1x".to_string()), 1, 2)));
+    assert_equal!(
+        read("(\"\\q\")"),
+        Err(error(ParseErrorKind::InvalidEscape("\\q".to_string()), 1, 3))
+    );
+}
+
+#[test]
+fn test_read_error_display() {
+    let error = read("(a\n  b").unwrap_err();
+    assert_equal!(error.to_string(), "1:1: list is never closed");
+}
+
+#[test]
+fn test_read_nested_lists() {
+    let cell = read("(a\n  (b (c)) ())").unwrap();
+    assert_equal!(cell, list!(a, [b, [c]], []));
+    assert_equal!(read("(nil ())").unwrap().values(), vec![Value::Nil, Value::List(Cell::nil())]);
+    assert_equal!(read("(a 'b)").unwrap(), list!(a, [quote, b]));
+    assert_equal!(read("'(a b)").unwrap(), list!(quote, [a, b]));
+}
+
+#[test]
+fn test_read_nested_errors() {
+    assert_equal!(read("(a (b c)"), Err(error(ParseErrorKind::UnclosedList, 1, 1)));
+    assert_equal!(read("(a\n  (b c)"), Err(error(ParseErrorKind::UnclosedList, 1, 1)));
+    assert_equal!(read("((a) (b"), Err(error(ParseErrorKind::UnclosedList, 1, 6)));
+}
+
+#[test]
+fn test_read_dotted_pairs() {
+    let cell = read("(a . b)").unwrap();
+    assert_equal!(cell.values(), vec![Value::from(symbol("a"))]);
+    assert_equal!(cell.end(), Some(&Value::from(symbol("b"))));
+    assert_equal!(cell, Cell::dotted(Value::from(symbol("a")), Value::from(symbol("b"))));
+
+    let cell = read("(() (()) (a . b))").unwrap();
+    assert_equal!(cell.len(), 3);
+    assert_equal!(cell.end(), None);
+    assert_equal!(cell.to_string(), "(() (()) (a . b))");
+
+    let cell = read("(1u8 2u8 . 3u8)").unwrap();
+    assert_equal!(cell.values(), vec![Value::Byte(1), Value::Byte(2)]);
+    assert_equal!(cell.end(), Some(&Value::Byte(3)));
+    assert_equal!(read("(a . (b c))").unwrap(), list!(a, b, c));
+    assert_equal!(read("(a . nil)").unwrap(), list!(a));
+    assert_equal!(read("(a . ())").unwrap(), list!(a));
+    assert_equal!(read("(a . (b . c))").unwrap().to_string(), "(a b . c)");
+    assert_equal!(
+        read("(a .5)").unwrap().values(),
+        vec![Value::from(symbol("a")), Value::from(symbol(".5"))]
+    );
+}
+
+#[test]
+fn test_read_dotted_pair_errors() {
+    assert_equal!(read("(. b)"), Err(error(ParseErrorKind::MisplacedDot, 1, 2)));
+    assert_equal!(read("(a .)"), Err(error(ParseErrorKind::MisplacedDot, 1, 4)));
+    assert_equal!(read("(a . b c)"), Err(error(ParseErrorKind::MisplacedDot, 1, 4)));
+    assert_equal!(read("(a . b . c)"), Err(error(ParseErrorKind::MisplacedDot, 1, 4)));
+    assert_equal!(read("(a . b"), Err(error(ParseErrorKind::UnclosedList, 1, 1)));
+    assert_equal!(read("."), Err(error(ParseErrorKind::MisplacedDot, 1, 1)));
+    assert_equal!(read("(a '.)"), Err(error(ParseErrorKind::MisplacedDot, 1, 5)));
+    assert_equal!(read("(. b)").unwrap_err().to_string(), "1:2: misplaced `.`");
+}
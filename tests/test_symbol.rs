@@ -0,0 +1,120 @@
+#![allow(unused)]
+use ds::sexp::{read, ParseError, ParseErrorKind};
+use ds::*;
+use k9::assert_equal;
+
+#[test]
+fn test_symbol_interning() {
+    let foo = symbol("foo");
+    assert_equal!(foo, symbol("foo"));
+    assert_equal!(foo.id(), symbol(&String::from("foo")).id());
+    assert_equal!(foo == symbol("bar"), false);
+    assert_equal!(foo.name(), "foo");
+    assert_equal!(Symbol::from("foo"), foo);
+}
+
+#[test]
+fn test_symbol_order_is_by_name() {
+    let zeta = symbol("order-zeta");
+    let alpha = symbol("order-alpha");
+    assert_equal!(alpha < zeta, true);
+
+    let mut values = vec![Value::from(zeta), Value::from(alpha), Value::from(symbol("order-mu"))];
+    values.sort();
+    assert_equal!(
+        values.iter().map(|value| value.to_string()).collect::<Vec<_>>(),
+        vec!["order-alpha", "order-mu", "order-zeta"]
+    );
+}
+
+#[test]
+fn test_symbol_value() {
+    let value = Value::from(symbol("foo"));
+    assert_equal!(value, Value::Symbol(symbol("foo")));
+    assert_equal!(value == Value::from("foo"), false);
+    assert_equal!(format!("{}", value), "foo");
+    assert_equal!(format!("{:?}", value), "foo");
+    assert_equal!(format!("{:?}", Value::from("foo")), "\"foo\"");
+    assert_equal!(Cell::from(symbol("foo")).head(), Some(value));
+}
+
+#[test]
+fn test_symbol_debug_escapes_unreadable_names() {
+    assert_equal!(format!("{:?}", symbol("with space")), "|with space|");
+    assert_equal!(format!("{:?}", symbol("nil")), "|nil|");
+    assert_equal!(format!("{:?}", symbol("42")), "|42|");
+    assert_equal!(format!("{:?}", symbol("")), "||");
+    assert_equal!(format!("{:?}", symbol("a|b\\c")), r"|a\|b\\c|");
+    assert_equal!(format!("{:?}", symbol("-")), "-");
+    assert_equal!(format!("{:?}", symbol("set!")), "set!");
+}
+
+#[test]
+fn test_list_macro_symbols() {
+    let cell = list!(head, "middle", 33u8, tail);
+    assert_equal!(cell.to_string(), r#"(head "middle" 33u8 tail)"#);
+    assert_equal!(
+        cell.values(),
+        vec![
+            Value::from(symbol("head")),
+            Value::from("middle"),
+            Value::Byte(33),
+            Value::from(symbol("tail")),
+        ]
+    );
+    assert_equal!(
+        list!(define, [square, x], [multiply, x, x]).to_string(),
+        "(define (square x) (multiply x x))"
+    );
+}
+
+#[test]
+fn test_read_symbols() {
+    let cell = read("(head \"middle\" 33u8 tail)").unwrap();
+    assert_equal!(cell, list!(head, "middle", 33u8, tail));
+    assert_equal!(cell.to_string(), "(head \"middle\" 33u8 tail)");
+    assert_equal!(
+        read("(|with space| |nil| |a\\|b|)").unwrap().values(),
+        vec![
+            Value::from(symbol("with space")),
+            Value::from(symbol("nil")),
+            Value::from(symbol("a|b")),
+        ]
+    );
+}
+
+#[test]
+fn test_read_symbols_round_trip() {
+    let cell = [
+        symbol("plain"),
+        symbol("with space"),
+        symbol("nil"),
+        symbol("-1"),
+        symbol("a|b\\c"),
+    ]
+    .into_iter()
+    .collect::<Cell>();
+    let text = cell.to_string();
+    assert_equal!(read(&text).unwrap(), cell);
+}
+
+#[test]
+fn test_read_symbol_errors() {
+    assert_equal!(
+        read("(a |b"),
+        Err(ParseError {
+            kind: ParseErrorKind::UnterminatedSymbol,
+            line: 1,
+            column: 4
+        })
+    );
+}
+
+#[test]
+fn test_symbol_across_threads() {
+    let ids = (0..8)
+        .map(|_| std::thread::spawn(|| symbol("shared-across-threads").id()))
+        .map(|handle| handle.join().unwrap())
+        .collect::<Vec<_>>();
+    assert_equal!(ids.iter().all(|id| *id == symbol("shared-across-threads").id()), true);
+}
@@ -0,0 +1,174 @@
+#![allow(unused)]
+use ds::*;
+use k9::assert_equal;
+
+pub fn tree<'t>() -> BinaryTree<'t> {
+    ///|||||||||||||||||||||||||||||||||||||||||||||\\\
+    ///                                             \\\
+    ///                     A                       \\\
+    ///                    / \                      \\\
+    ///                   /   \                     \\\
+    ///                  B     C                    \\\
+    ///                 / \     \                   \\\
+    ///                D   E     F                  \\\
+    ///                   /                         \\\
+    ///                  G                          \\\
+    ///                                             \\\
+    let mut tree = BinaryTree::new(Value::from("A"));
+    let node_b = tree.node(Value::from("B"));
+    let node_c = tree.node(Value::from("C"));
+    let node_d = tree.node(Value::from("D"));
+    let node_e = tree.node(Value::from("E"));
+    let node_f = tree.node(Value::from("F"));
+    let node_g = tree.node(Value::from("G"));
+    let root = tree.root_mut().unwrap();
+    root.set_left(node_b);
+    root.set_right(node_c);
+    node_b.set_left(node_d);
+    node_b.set_right(node_e);
+    node_e.set_left(node_g);
+    node_c.set_right(node_f);
+    tree
+}
+
+fn values<'t>(nodes: impl Iterator<Item = &'t Node<'t>>) -> Vec<Value<'t>> {
+    nodes.map(Node::item).collect()
+}
+
+fn expected<'t>(values: &[&'t str]) -> Vec<Value<'t>> {
+    values.iter().map(|value| Value::from(*value)).collect()
+}
+
+fn refs<'t>(tree: &BinaryTree<'t>) -> Vec<usize> {
+    let mut refs = Vec::new();
+    let mut stack = vec![tree.root().unwrap()];
+    while let Some(node) = stack.pop() {
+        refs.push(node.refs());
+        stack.extend(node.left());
+        stack.extend(node.right());
+    }
+    refs
+}
+
+#[test]
+fn test_traversal_inorder() {
+    let tree = tree();
+    let root = tree.root().unwrap();
+    assert_equal!(
+        values(root.iter_inorder()),
+        expected(&["D", "B", "G", "E", "A", "C", "F"])
+    );
+}
+
+#[test]
+fn test_traversal_preorder() {
+    let tree = tree();
+    let root = tree.root().unwrap();
+    assert_equal!(
+        values(root.iter_preorder()),
+        expected(&["A", "B", "D", "E", "G", "C", "F"])
+    );
+}
+
+#[test]
+fn test_traversal_postorder() {
+    let tree = tree();
+    let root = tree.root().unwrap();
+    assert_equal!(
+        values(root.iter_postorder()),
+        expected(&["D", "G", "E", "B", "F", "C", "A"])
+    );
+}
+
+#[test]
+fn test_traversal_level_order() {
+    let tree = tree();
+    let root = tree.root().unwrap();
+    assert_equal!(
+        values(root.iter_level_order()),
+        expected(&["A", "B", "C", "D", "E", "F", "G"])
+    );
+}
+
+#[test]
+fn test_traversal_values() {
+    let tree = tree();
+    assert_equal!(
+        tree.iter_inorder().values().collect::<Vec<_>>(),
+        expected(&["D", "B", "G", "E", "A", "C", "F"])
+    );
+    assert_equal!(
+        tree.iter_preorder().values().collect::<Vec<_>>(),
+        expected(&["A", "B", "D", "E", "G", "C", "F"])
+    );
+    assert_equal!(
+        tree.iter_postorder().values().collect::<Vec<_>>(),
+        expected(&["D", "G", "E", "B", "F", "C", "A"])
+    );
+    assert_equal!(
+        tree.iter_level_order().values().collect::<Vec<_>>(),
+        expected(&["A", "B", "C", "D", "E", "F", "G"])
+    );
+}
+
+#[test]
+fn test_traversal_subtree_stays_within_subtree() {
+    let tree = tree();
+    let node_b = tree.root().unwrap().left().unwrap();
+    assert_equal!(values(node_b.iter_inorder()), expected(&["D", "B", "G", "E"]));
+    assert_equal!(values(node_b.iter_preorder()), expected(&["B", "D", "E", "G"]));
+    assert_equal!(values(node_b.iter_postorder()), expected(&["D", "G", "E", "B"]));
+    assert_equal!(values(node_b.iter_level_order()), expected(&["B", "D", "E", "G"]));
+
+    let node_f = tree.root().unwrap().right().unwrap().right().unwrap();
+    assert_equal!(values(node_f.iter_inorder()), expected(&["F"]));
+    assert_equal!(values(node_f.iter_postorder()), expected(&["F"]));
+}
+
+#[test]
+fn test_traversal_does_not_change_refs() {
+    let mut tree = tree();
+    let before = refs(&tree);
+
+    tree.iter_inorder().count();
+    tree.iter_preorder().count();
+    tree.iter_postorder().count();
+    tree.iter_level_order().values().count();
+    tree.iter_inorder_mut().count();
+    tree.iter_preorder_mut().count();
+    tree.iter_postorder_mut().count();
+    tree.iter_level_order_mut().count();
+
+    assert_equal!(refs(&tree), before);
+}
+
+#[test]
+fn test_traversal_mut_changes_items() {
+    let mut tree = BinaryTree::nil();
+    for value in [4u8, 2, 6, 1, 3, 5, 7] {
+        tree.insert(Value::from(value));
+    }
+    for node in tree.iter_inorder_mut() {
+        let value = node.item();
+        *node.item.inner_mut() = Value::from(value.to_string() + "!");
+    }
+    assert_equal!(
+        tree.iter_inorder().values().map(|value| value.to_string()).collect::<Vec<_>>(),
+        vec!["1!", "2!", "3!", "4!", "5!", "6!", "7!"]
+    );
+
+    for node in tree.root_mut().unwrap().left_mut().unwrap().iter_level_order_mut() {
+        *node.item.inner_mut() = Value::nil();
+    }
+    assert_equal!(tree.iter_preorder().values().filter(Value::is_nil).count(), 3);
+}
+
+#[test]
+fn test_traversal_empty_tree() {
+    let mut tree = BinaryTree::nil();
+    assert_equal!(tree.iter_inorder().count(), 0);
+    assert_equal!(tree.iter_preorder().count(), 0);
+    assert_equal!(tree.iter_postorder().count(), 0);
+    assert_equal!(tree.iter_level_order().count(), 0);
+    assert_equal!(tree.iter_inorder_mut().count(), 0);
+}
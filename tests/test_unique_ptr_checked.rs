@@ -0,0 +1,111 @@
+#![allow(unused)]
+use ds::*;
+use k9::assert_equal;
+
+#[test]
+fn test_unique_pointer_try_accessors_on_null() {
+    let mut null = UniquePointer::<Value>::null();
+    assert_equal!(null.try_inner_ref().err(), Some(PointerError::Null));
+    assert_equal!(null.try_inner_mut().err(), Some(PointerError::Null));
+    assert_equal!(null.try_read(), Err(PointerError::Null));
+}
+
+#[test]
+fn test_unique_pointer_try_accessors_on_unwritten() {
+    let mut value = UniquePointer::<Value>::null();
+    value.alloc();
+    assert_equal!(value.try_inner_ref().err(), Some(PointerError::NotWritten));
+    assert_equal!(value.try_inner_mut().err(), Some(PointerError::NotWritten));
+    assert_equal!(value.try_read(), Err(PointerError::NotWritten));
+
+    assert_equal!(value.try_write(Value::from("string")), Ok(()));
+    assert_equal!(value.try_read(), Ok(Value::from("string")));
+    value.dealloc(true);
+}
+
+#[test]
+fn test_unique_pointer_try_accessors_on_written() {
+    let mut value = UniquePointer::from(Value::from("string"));
+    assert_equal!(value.try_inner_ref(), Ok(&Value::from("string")));
+    *value.try_inner_mut().unwrap() = Value::UInt(1000);
+    assert_equal!(value.try_read(), Ok(Value::UInt(1000)));
+
+    assert_equal!(value.try_write(Value::UInt(2000)), Ok(()));
+    assert_equal!(value.read(), Value::UInt(2000));
+    value.dealloc(true);
+    assert_equal!(value.try_read(), Err(PointerError::Null));
+}
+
+#[test]
+fn test_unique_pointer_try_accessors_on_freed_clone() {
+    let mut value = UniquePointer::from(Value::from("string"));
+    let mut clone = value.clone();
+    let mut other = clone.clone();
+
+    value.dealloc(false);
+    assert_equal!(clone.is_freed(), true);
+    assert_equal!(clone.is_written(), false);
+    assert_equal!(clone.as_ref(), None);
+    assert_equal!(clone.try_inner_ref().err(), Some(PointerError::Freed));
+    assert_equal!(clone.try_inner_mut().err(), Some(PointerError::Freed));
+    assert_equal!(clone.try_read(), Err(PointerError::Freed));
+    assert_equal!(clone.try_write(Value::UInt(1000)), Err(PointerError::Freed));
+
+    clone.dealloc(true);
+    assert_equal!(clone.is_null(), true);
+    assert_equal!(clone.is_freed(), false);
+    assert_equal!(other.try_read(), Err(PointerError::Freed));
+    other.dealloc(false);
+    assert_equal!(other.is_null(), true);
+}
+
+#[test]
+fn test_unique_pointer_try_write_aliased() {
+    let data = Value::from("string");
+    let mut borrowed =
+        UniquePointer::copy_from_ref(&data, 1, UniquePointer::raw_addr_of_ref(&data));
+    assert_equal!(borrowed.try_read(), Ok(Value::from("string")));
+    assert_equal!(borrowed.try_write(Value::UInt(1000)), Err(PointerError::Aliased));
+    assert_equal!(data, Value::from("string"));
+}
+
+#[test]
+fn test_pointer_error_display() {
+    assert_equal!(PointerError::Null.to_string(), "is NULL");
+    assert_equal!(PointerError::NotWritten.to_string(), "not written");
+    assert_equal!(PointerError::Freed.to_string(), "freed by another reference");
+    assert_equal!(PointerError::Aliased.to_string(), "aliases a value it does not own");
+}
+
+#[test]
+#[should_panic(expected = "is NULL")]
+fn test_unique_pointer_inner_ref_panics_when_null() {
+    let null = UniquePointer::<Value>::null();
+    null.inner_ref();
+}
+
+#[test]
+#[should_panic(expected = "not written")]
+fn test_unique_pointer_read_panics_when_not_written() {
+    let mut value = UniquePointer::<Value>::null();
+    value.alloc();
+    value.read();
+}
+
+#[test]
+#[should_panic(expected = "freed by another reference")]
+fn test_unique_pointer_inner_mut_panics_when_freed() {
+    let mut value = UniquePointer::from(Value::from("string"));
+    let mut clone = value.clone();
+    value.dealloc(false);
+    clone.inner_mut();
+}
+
+#[test]
+#[should_panic(expected = "aliases a value it does not own")]
+fn test_unique_pointer_write_panics_when_aliased() {
+    let data = Value::from("string");
+    let mut borrowed =
+        UniquePointer::copy_from_ref(&data, 1, UniquePointer::raw_addr_of_ref(&data));
+    borrowed.write(Value::UInt(1000));
+}
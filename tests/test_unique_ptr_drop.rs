@@ -0,0 +1,251 @@
+#![allow(unused)]
+use std::cell::RefCell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+use ds::*;
+use k9::assert_equal;
+
+/// `Tracked` records the key of each original value dropped, clones
+/// are dropped without being recorded
+#[derive(Debug)]
+struct Tracked {
+    key: u64,
+    drops: Rc<RefCell<Vec<u64>>>,
+    original: bool,
+    panics: bool,
+}
+
+impl Tracked {
+    fn new(key: u64, drops: &Rc<RefCell<Vec<u64>>>) -> Tracked {
+        Tracked {
+            key,
+            drops: drops.clone(),
+            original: true,
+            panics: false,
+        }
+    }
+
+    fn panicking(key: u64, drops: &Rc<RefCell<Vec<u64>>>) -> Tracked {
+        let mut tracked = Tracked::new(key, drops);
+        tracked.panics = true;
+        tracked
+    }
+}
+
+impl Clone for Tracked {
+    fn clone(&self) -> Tracked {
+        Tracked {
+            key: self.key,
+            drops: self.drops.clone(),
+            original: false,
+            panics: false,
+        }
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        if self.original {
+            self.drops.borrow_mut().push(self.key);
+        }
+        if self.panics {
+            panic!("dropping {}", self.key);
+        }
+    }
+}
+
+impl PartialEq for Tracked {
+    fn eq(&self, other: &Tracked) -> bool {
+        self.key == other.key
+    }
+}
+impl Eq for Tracked {}
+impl PartialOrd for Tracked {
+    fn partial_cmp(&self, other: &Tracked) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Tracked {
+    fn cmp(&self, other: &Tracked) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+impl std::fmt::Display for Tracked {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.key)
+    }
+}
+
+/// `Unwritten` panics when dropped, such that dropping memory which
+/// was never written to fails the test
+struct Unwritten {
+    data: u64,
+}
+
+impl Drop for Unwritten {
+    fn drop(&mut self) {
+        panic!("dropped an unwritten value");
+    }
+}
+
+fn drops() -> Rc<RefCell<Vec<u64>>> {
+    Rc::new(RefCell::new(Vec::new()))
+}
+
+#[test]
+fn test_unique_pointer_dealloc_drops_value_once() {
+    let drops = drops();
+    let mut value = UniquePointer::from(Tracked::new(1, &drops));
+    assert_equal!(drops.borrow().clone(), Vec::<u64>::new());
+
+    value.dealloc(true);
+    assert_equal!(drops.borrow().clone(), vec![1]);
+    assert_equal!(value.is_null(), true);
+
+    value.dealloc(true);
+    value.dealloc(false);
+    assert_equal!(drops.borrow().clone(), vec![1]);
+}
+
+#[test]
+fn test_unique_pointer_hard_dealloc_drops_value_once() {
+    let drops = drops();
+    let mut value = UniquePointer::from(Tracked::new(1, &drops));
+    value.dealloc(false);
+    assert_equal!(drops.borrow().clone(), vec![1]);
+    value.dealloc(false);
+    assert_equal!(drops.borrow().clone(), vec![1]);
+}
+
+#[test]
+fn test_unique_pointer_clone_keeps_value_until_last_reference() {
+    let drops = drops();
+    let mut value = UniquePointer::from(Tracked::new(1, &drops));
+    let mut clone = value.clone();
+    assert_equal!(value.refs(), 2);
+
+    value.dealloc(true);
+    assert_equal!(value.is_null(), true);
+    assert_equal!(drops.borrow().clone(), Vec::<u64>::new());
+    assert_equal!(clone.refs(), 1);
+    assert_equal!(clone.inner_ref().key, 1);
+
+    clone.dealloc(true);
+    assert_equal!(drops.borrow().clone(), vec![1]);
+}
+
+#[test]
+fn test_unique_pointer_owner_outlives_clones() {
+    let drops = drops();
+    let mut value = UniquePointer::from(Tracked::new(1, &drops));
+    let mut clone = value.clone();
+
+    clone.dealloc(true);
+    assert_equal!(clone.is_null(), true);
+    assert_equal!(drops.borrow().clone(), Vec::<u64>::new());
+
+    value.dealloc(true);
+    assert_equal!(drops.borrow().clone(), vec![1]);
+}
+
+#[test]
+fn test_unique_pointer_hard_dealloc_of_shared_copy() {
+    let drops = drops();
+    let mut value = UniquePointer::from(Tracked::new(1, &drops));
+    let mut clone = value.clone();
+
+    clone.dealloc(false);
+    assert_equal!(clone.is_null(), true);
+    assert_equal!(drops.borrow().clone(), Vec::<u64>::new());
+    assert_equal!(value.inner_ref().key, 1);
+
+    value.dealloc(true);
+    assert_equal!(drops.borrow().clone(), vec![1]);
+}
+
+#[test]
+fn test_unique_pointer_write_drops_previous_value() {
+    let drops = drops();
+    let mut value = UniquePointer::from(Tracked::new(1, &drops));
+    let clone = value.clone();
+
+    value.write(Tracked::new(2, &drops));
+    assert_equal!(drops.borrow().clone(), vec![1]);
+    assert_equal!(clone.inner_ref().key, 2);
+
+    value.dealloc(false);
+    assert_equal!(drops.borrow().clone(), vec![1, 2]);
+}
+
+#[test]
+fn test_unique_pointer_read_does_not_drop_value() {
+    let drops = drops();
+    let mut value = UniquePointer::from(Tracked::new(1, &drops));
+    for _ in 0..3 {
+        let copy = value.read();
+        assert_equal!(copy.key, 1);
+    }
+    let copy = UniquePointer::from_ref(value.inner_ref());
+    assert_equal!(drops.borrow().clone(), Vec::<u64>::new());
+
+    value.dealloc(true);
+    assert_equal!(drops.borrow().clone(), vec![1]);
+}
+
+#[test]
+fn test_unique_pointer_unwritten_is_not_dropped() {
+    let mut value = UniquePointer::<Unwritten>::null();
+    value.alloc();
+    assert_equal!(value.is_allocated(), true);
+    assert_equal!(value.is_written(), false);
+
+    value.dealloc(true);
+    assert_equal!(value.is_null(), true);
+}
+
+#[test]
+fn test_unique_pointer_panicking_drop() {
+    let drops = drops();
+    let mut value = UniquePointer::from(Tracked::panicking(1, &drops));
+
+    let result = catch_unwind(AssertUnwindSafe(|| value.dealloc(true)));
+    assert_equal!(result.is_err(), true);
+    assert_equal!(drops.borrow().clone(), vec![1]);
+    assert_equal!(value.is_null(), true);
+    assert_equal!(value.is_written(), false);
+
+    value.dealloc(true);
+    assert_equal!(drops.borrow().clone(), vec![1]);
+}
+
+#[test]
+fn test_unique_pointer_panicking_drop_releases_weak_pointers() {
+    let drops = drops();
+    let mut value = UniquePointer::from(Tracked::panicking(1, &drops));
+    let weak = value.downgrade();
+
+    let result = catch_unwind(AssertUnwindSafe(|| value.dealloc(true)));
+    assert_equal!(result.is_err(), true);
+    assert_equal!(weak.upgrade().is_none(), true);
+}
+
+#[test]
+fn test_binary_tree_drops_each_item_once() {
+    let drops = drops();
+    {
+        let mut tree = BinaryTree::new(Tracked::new(4, &drops));
+        for key in [2, 6, 1, 3, 5, 7] {
+            tree.insert(Tracked::new(key, &drops));
+        }
+        assert_equal!(tree.len(), 7);
+
+        let key = Tracked::new(2, &drops).clone();
+        assert_equal!(tree.delete(&key).map(|item| item.key), Some(2));
+        assert_equal!(drops.borrow().clone(), vec![2, 2]);
+        drops.borrow_mut().clear();
+    }
+    let mut dropped = drops.borrow().clone();
+    dropped.sort();
+    assert_equal!(dropped, vec![1, 3, 4, 5, 6, 7]);
+}
@@ -0,0 +1,194 @@
+#![allow(unused)]
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ds::*;
+use k9::assert_equal;
+
+trait Shape {
+    fn area(&self) -> u64;
+}
+
+struct Square {
+    side: u64,
+    drops: Rc<AtomicUsize>,
+}
+
+impl Shape for Square {
+    fn area(&self) -> u64 {
+        self.side * self.side
+    }
+}
+
+impl Drop for Square {
+    fn drop(&mut self) {
+        self.drops.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// `Point` is a Zero-Sized-Type counting its drops in `POINT_DROPS`,
+/// which is thread-local such that tests running in parallel do not
+/// count each other's drops
+#[derive(Clone, Debug, PartialEq)]
+struct Point;
+
+thread_local! {
+    static POINT_DROPS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn point_drops() -> usize {
+    POINT_DROPS.get()
+}
+
+impl Drop for Point {
+    fn drop(&mut self) {
+        POINT_DROPS.set(POINT_DROPS.get() + 1);
+    }
+}
+
+impl Shape for Point {
+    fn area(&self) -> u64 {
+        0
+    }
+}
+
+#[test]
+fn test_unique_pointer_from_str() {
+    let mut string = UniquePointer::from_str("string");
+    assert_equal!(string.is_null(), false);
+    assert_equal!(string.is_written(), true);
+    assert_equal!(string.inner_ref(), "string");
+    assert_equal!(string.len(), 6);
+    assert_equal!(&*string, "string");
+
+    string.inner_mut().make_ascii_uppercase();
+    assert_equal!(string.as_ref(), Some("STRING"));
+
+    string.dealloc(true);
+    assert_equal!(string.is_null(), true);
+    assert_equal!(string.as_ref(), None);
+}
+
+#[test]
+fn test_unique_pointer_from_str_empty() {
+    let mut string = UniquePointer::from_str("");
+    assert_equal!(string.is_null(), false);
+    assert_equal!(string.inner_ref(), "");
+    string.dealloc(true);
+    assert_equal!(string.is_null(), true);
+}
+
+#[test]
+fn test_unique_pointer_from_slice() {
+    let mut values = UniquePointer::from_slice(&[Value::from("a"), Value::UInt(1000)]);
+    assert_equal!(values.len(), 2);
+    assert_equal!(values[0], Value::from("a"));
+
+    values.inner_mut()[1] = Value::UInt(2000);
+    assert_equal!(values.inner_ref(), &[Value::from("a"), Value::UInt(2000)][..]);
+
+    let mut clone = values.clone();
+    assert_equal!(clone.refs(), 2);
+    assert_equal!(clone.addr(), values.addr());
+    assert_equal!(clone.inner_ref(), values.inner_ref());
+
+    values.dealloc(true);
+    assert_equal!(clone.refs(), 1);
+    assert_equal!(clone[1], Value::UInt(2000));
+    clone.dealloc(true);
+}
+
+#[test]
+fn test_unique_pointer_from_slice_drops_items() {
+    let rc = Rc::new(());
+    let mut items = UniquePointer::from_slice(&[rc.clone(), rc.clone()]);
+    assert_equal!(Rc::strong_count(&rc), 3);
+
+    items.dealloc(true);
+    assert_equal!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_unique_pointer_dyn_trait() {
+    let drops = Rc::new(AtomicUsize::new(0));
+    let shape: Box<dyn Shape> = Box::new(Square {
+        side: 3,
+        drops: drops.clone(),
+    });
+    let mut shape = UniquePointer::from_box(shape);
+    assert_equal!(shape.area(), 9);
+    assert_equal!(shape.inner_ref().area(), 9);
+
+    let clone = shape.clone();
+    assert_equal!(clone.area(), 9);
+
+    shape.dealloc(true);
+    assert_equal!(drops.load(Ordering::Relaxed), 0);
+
+    let mut clone = clone;
+    clone.dealloc(true);
+    assert_equal!(drops.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn test_unique_pointer_from_boxed_value() {
+    let mut value = UniquePointer::<Value>::from(Box::new(Value::from("boxed")));
+    assert_equal!(value.read(), Value::from("boxed"));
+    value.dealloc(true);
+    assert_equal!(value.is_null(), true);
+}
+
+#[test]
+fn test_unique_pointer_zero_sized_type() {
+    let before = point_drops();
+    let mut point = UniquePointer::from(Point);
+    assert_equal!(point.is_null(), false);
+    assert_equal!(point.is_allocated(), true);
+    assert_equal!(point.is_written(), true);
+    assert_equal!(point.addr() % std::mem::align_of::<Point>(), 0);
+
+    let copy = point.read();
+    assert_equal!(point_drops(), before);
+    drop(copy);
+    assert_equal!(point_drops(), before + 1);
+
+    point.dealloc(true);
+    assert_equal!(point.is_null(), true);
+    assert_equal!(point_drops(), before + 2);
+}
+
+#[test]
+fn test_unique_pointer_zero_sized_unit() {
+    let mut unit = UniquePointer::from(());
+    assert_equal!(unit.is_written(), true);
+    assert_equal!(unit.read(), ());
+    unit.write(());
+    unit.dealloc(false);
+    assert_equal!(unit.is_null(), true);
+}
+
+#[test]
+fn test_unique_pointer_zero_sized_alloc_is_aligned() {
+    let mut aligned = UniquePointer::<[u64; 0]>::null();
+    aligned.alloc();
+    assert_equal!(aligned.is_allocated(), true);
+    assert_equal!(aligned.is_written(), false);
+    assert_equal!(aligned.addr() % std::mem::align_of::<u64>(), 0);
+    aligned.dealloc(true);
+    assert_equal!(aligned.is_null(), true);
+}
+
+#[test]
+fn test_unique_pointer_zero_sized_slice_and_trait_object() {
+    let mut units = UniquePointer::from_slice(&[(), (), ()]);
+    assert_equal!(units.len(), 3);
+    units.dealloc(true);
+
+    let before = point_drops();
+    let shape: Box<dyn Shape> = Box::new(Point);
+    let mut shape = UniquePointer::from_box(shape);
+    assert_equal!(shape.area(), 0);
+    shape.dealloc(true);
+    assert_equal!(point_drops(), before + 1);
+}
@@ -0,0 +1,136 @@
+#![allow(unused)]
+use std::cmp::Ordering;
+
+use ds::*;
+use k9::assert_equal;
+
+#[test]
+fn test_value_add_same_variant() {
+    assert_equal!(Value::Byte(1) + Value::Byte(2), Ok(Value::Byte(3)));
+    assert_equal!(Value::UInt(1) + Value::UInt(2), Ok(Value::UInt(3)));
+    assert_equal!(Value::Int(-1) + Value::Int(2), Ok(Value::Int(1)));
+    assert_equal!(Value::Float(0.5) + Value::Float(0.25), Ok(Value::Float(0.75)));
+}
+
+#[test]
+fn test_value_promotion() {
+    assert_equal!(Value::Byte(1) + Value::UInt(2), Ok(Value::UInt(3)));
+    assert_equal!(Value::UInt(2) + Value::Int(-3), Ok(Value::Int(-1)));
+    assert_equal!(Value::Int(-1) + Value::Byte(1), Ok(Value::Int(0)));
+    assert_equal!(Value::Byte(1) + Value::Float(0.5), Ok(Value::Float(1.5)));
+    assert_equal!(Value::Int(3) / Value::Float(2.0), Ok(Value::Float(1.5)));
+}
+
+#[test]
+fn test_value_overflow_widens() {
+    assert_equal!(Value::Byte(200) + Value::Byte(100), Ok(Value::UInt(300)));
+    assert_equal!(Value::Byte(16) * Value::Byte(16), Ok(Value::UInt(256)));
+    assert_equal!(Value::Byte(1) - Value::Byte(2), Ok(Value::Int(-1)));
+    assert_equal!(Value::UInt(1) - Value::UInt(2), Ok(Value::Int(-1)));
+    assert_equal!(
+        Value::UInt(i64::MAX as u64) + Value::UInt(1),
+        Ok(Value::UInt(i64::MAX as u64 + 1))
+    );
+}
+
+#[test]
+fn test_value_overflow_mixed_signs_falls_back() {
+    assert_equal!(Value::UInt(u64::MAX) + Value::Int(0), Ok(Value::UInt(u64::MAX)));
+    assert_equal!(Value::Int(1) + Value::UInt(u64::MAX - 1), Ok(Value::UInt(u64::MAX)));
+    assert_equal!(Value::Int(i64::MAX) + Value::Byte(1), Ok(Value::UInt(i64::MAX as u64 + 1)));
+    assert_equal!(Value::UInt(u64::MAX) - Value::Int(1), Ok(Value::UInt(u64::MAX - 1)));
+    assert_equal!(Value::UInt(u64::MAX) + Value::Int(1), Err(ValueError::Overflow));
+    assert_equal!(Value::Int(-1) - Value::UInt(u64::MAX), Err(ValueError::Overflow));
+}
+
+#[test]
+fn test_value_overflow_errors() {
+    assert_equal!(Value::UInt(u64::MAX) + Value::Byte(1), Err(ValueError::Overflow));
+    assert_equal!(Value::Int(i64::MIN) - Value::Byte(1), Err(ValueError::Overflow));
+    assert_equal!(Value::UInt(u64::MAX) * Value::UInt(u64::MAX), Err(ValueError::Overflow));
+    assert_equal!(Value::Int(i64::MIN) / Value::Int(-1), Err(ValueError::Overflow));
+    assert_equal!(-Value::Int(i64::MIN), Err(ValueError::Overflow));
+}
+
+#[test]
+fn test_value_div_rem() {
+    assert_equal!(Value::Byte(7) / Value::Byte(2), Ok(Value::Byte(3)));
+    assert_equal!(Value::Int(-7) / Value::Byte(2), Ok(Value::Int(-3)));
+    assert_equal!(Value::Int(-7) % Value::Byte(2), Ok(Value::Int(-1)));
+    assert_equal!(Value::UInt(7) % Value::UInt(4), Ok(Value::UInt(3)));
+    assert_equal!(Value::Float(7.5) % Value::Byte(2), Ok(Value::Float(1.5)));
+}
+
+#[test]
+fn test_value_division_by_zero() {
+    assert_equal!(Value::Byte(1) / Value::Byte(0), Err(ValueError::DivisionByZero));
+    assert_equal!(Value::Int(1) % Value::UInt(0), Err(ValueError::DivisionByZero));
+    assert_equal!(Value::Int(1) / Value::Float(0.0), Ok(Value::Float(f64::INFINITY)));
+}
+
+#[test]
+fn test_value_neg() {
+    assert_equal!(-Value::Byte(5), Ok(Value::Int(-5)));
+    assert_equal!(-Value::Byte(0), Ok(Value::Byte(0)));
+    assert_equal!(-Value::Int(-5), Ok(Value::Int(5)));
+    assert_equal!(-Value::UInt(u64::MAX), Err(ValueError::Overflow));
+    assert_equal!(-Value::Float(1.5), Ok(Value::Float(-1.5)));
+}
+
+#[test]
+fn test_value_arithmetic_on_references() {
+    let a = Value::Byte(2);
+    let b = Value::UInt(3);
+    assert_equal!(&a + &b, Ok(Value::UInt(5)));
+    assert_equal!(&a * &b, Ok(Value::UInt(6)));
+    assert_equal!(-&a, Ok(Value::Int(-2)));
+    assert_equal!(a, Value::Byte(2));
+}
+
+#[test]
+fn test_value_arithmetic_not_numeric() {
+    assert_equal!(
+        Value::from("1") + Value::Byte(1),
+        Err(ValueError::NotNumeric("\"1\"".to_string()))
+    );
+    assert_equal!(Value::Byte(1) * Value::Nil, Err(ValueError::NotNumeric("nil".to_string())));
+    assert_equal!(-Value::Bool(true), Err(ValueError::NotNumeric("true".to_string())));
+    assert_equal!(
+        (Value::List(list!(1u8)) - Value::Byte(1)).unwrap_err().to_string(),
+        "(1u8) is not a number"
+    );
+}
+
+#[test]
+fn test_value_arithmetic_chained() {
+    let sum = [Value::Byte(250), Value::Byte(10), Value::Int(-300), Value::UInt(40)]
+        .into_iter()
+        .try_fold(Value::Byte(0), |sum, value| sum + value);
+    assert_equal!(sum, Ok(Value::Int(0)));
+}
+
+#[test]
+fn test_value_numeric_cmp() {
+    assert_equal!(Value::Int(1) < Value::Byte(200), false);
+    assert_equal!(Value::Int(1).numeric_cmp(&Value::Byte(200)), Some(Ordering::Less));
+    assert_equal!(Value::Int(-1).numeric_cmp(&Value::UInt(u64::MAX)), Some(Ordering::Less));
+    assert_equal!(Value::Byte(7).numeric_cmp(&Value::Int(7)), Some(Ordering::Equal));
+    assert_equal!(Value::Float(2.5).numeric_cmp(&Value::Byte(2)), Some(Ordering::Greater));
+    assert_equal!(Value::Float(f64::NAN).numeric_cmp(&Value::Byte(2)), None);
+    assert_equal!(Value::Nil.numeric_cmp(&Value::Byte(2)), None);
+
+    let mut values = vec![Value::Byte(200), Value::Int(-5), Value::Float(3.5), Value::UInt(1)];
+    values.sort_by(|a, b| a.numeric_cmp(b).unwrap());
+    assert_equal!(
+        values,
+        vec![Value::Int(-5), Value::UInt(1), Value::Float(3.5), Value::Byte(200)]
+    );
+}
+
+#[test]
+fn test_value_is_number() {
+    assert_equal!(Value::Byte(1).is_number(), true);
+    assert_equal!(Value::Float(f64::NAN).is_number(), true);
+    assert_equal!(Value::Char('1').is_number(), false);
+    assert_equal!(Value::from(symbol("one")).is_number(), false);
+}
@@ -0,0 +1,147 @@
+#![allow(unused)]
+use std::borrow::Cow;
+
+use ds::*;
+use k9::assert_equal;
+
+#[test]
+fn test_value_kind() {
+    assert_equal!(Value::Nil.kind(), ValueKind::Nil);
+    assert_equal!(Value::from("a").kind(), ValueKind::String);
+    assert_equal!(Value::from(symbol("a")).kind(), ValueKind::Symbol);
+    assert_equal!(Value::Byte(1).kind(), ValueKind::Byte);
+    assert_equal!(Value::UInt(1).kind(), ValueKind::UInt);
+    assert_equal!(Value::Int(1).kind(), ValueKind::Int);
+    assert_equal!(Value::Float(1.0).kind(), ValueKind::Float);
+    assert_equal!(Value::Bool(true).kind(), ValueKind::Bool);
+    assert_equal!(Value::Char('a').kind(), ValueKind::Char);
+    assert_equal!(Value::List(list!(1u8)).kind(), ValueKind::List);
+    assert_equal!(ValueKind::Byte.to_string(), "u8");
+}
+
+#[test]
+fn test_value_kind_follows_value_order() {
+    let mut values = vec![Value::List(Cell::nil()), Value::Char('a'), Value::Int(-1), Value::Nil];
+    values.sort();
+    let kinds = values.iter().map(Value::kind).collect::<Vec<_>>();
+    let mut sorted = kinds.clone();
+    sorted.sort();
+    assert_equal!(kinds, sorted);
+}
+
+#[test]
+fn test_try_from_value_integers() {
+    assert_equal!(u8::try_from(Value::Byte(7)), Ok(7));
+    assert_equal!(u8::try_from(Value::UInt(7)), Ok(7));
+    assert_equal!(u64::try_from(Value::Byte(7)), Ok(7));
+    assert_equal!(i64::try_from(Value::UInt(7)), Ok(7));
+    assert_equal!(i64::try_from(&Value::Int(-7)), Ok(-7));
+    assert_equal!(u64::try_from(&Value::UInt(u64::MAX)), Ok(u64::MAX));
+
+    let cell = Cell::from(5u64);
+    assert_equal!(u64::try_from(cell.head().unwrap()), Ok(5));
+}
+
+#[test]
+fn test_try_from_value_out_of_range() {
+    assert_equal!(
+        u8::try_from(Value::UInt(300)),
+        Err(ValueError::OutOfRange {
+            expected: ValueKind::Byte,
+            actual: ValueKind::UInt,
+            value: "300u64".to_string()
+        })
+    );
+    assert_equal!(
+        u64::try_from(Value::Int(-1)).unwrap_err().to_string(),
+        "i64 -1i64 does not fit u64"
+    );
+    assert_equal!(
+        i64::try_from(Value::UInt(u64::MAX)).unwrap_err().to_string(),
+        "u64 18446744073709551615u64 does not fit i64"
+    );
+}
+
+#[test]
+fn test_try_from_value_mismatch() {
+    assert_equal!(
+        u8::try_from(Value::from("7")),
+        Err(ValueError::Mismatch {
+            expected: ValueKind::Byte,
+            actual: ValueKind::String
+        })
+    );
+    assert_equal!(
+        String::try_from(Value::Byte(7)).unwrap_err().to_string(),
+        "expected string, found u8"
+    );
+    assert_equal!(
+        <&str>::try_from(&Value::from(symbol("a"))),
+        Err(ValueError::Mismatch {
+            expected: ValueKind::String,
+            actual: ValueKind::Symbol
+        })
+    );
+    assert_equal!(
+        i64::try_from(Value::Float(1.0)),
+        Err(ValueError::Mismatch {
+            expected: ValueKind::Int,
+            actual: ValueKind::Float
+        })
+    );
+}
+
+#[test]
+fn test_try_from_value_strings() {
+    let value = Value::from(String::from("owned"));
+    assert_equal!(String::try_from(&value), Ok("owned".to_string()));
+    assert_equal!(<&str>::try_from(&value), Ok("owned"));
+    assert_equal!(String::try_from(value), Ok("owned".to_string()));
+
+    let text = String::from("borrowed");
+    let value = Value::from(text.as_str());
+    let borrowed: Result<&str, ValueError> = value.try_into();
+    assert_equal!(borrowed, Ok("borrowed"));
+
+    let value = Value::String(Cow::Owned(String::from("owned")));
+    assert_equal!(<&str>::try_from(value), Err(ValueError::NotBorrowed));
+}
+
+#[test]
+fn test_try_from_value_scalars() {
+    assert_equal!(f64::try_from(Value::Float(1.5)), Ok(1.5));
+    assert_equal!(bool::try_from(&Value::Bool(true)), Ok(true));
+    assert_equal!(char::try_from(Value::Char('a')), Ok('a'));
+    assert_equal!(
+        bool::try_from(Value::Nil),
+        Err(ValueError::Mismatch {
+            expected: ValueKind::Bool,
+            actual: ValueKind::Nil
+        })
+    );
+}
+
+#[test]
+fn test_try_from_value_option() {
+    assert_equal!(Option::<u8>::try_from(Value::Nil), Ok(None));
+    assert_equal!(Option::<u8>::try_from(Value::Byte(1)), Ok(Some(1)));
+    assert_equal!(Option::<String>::try_from(&Value::Nil), Ok(None));
+    assert_equal!(Option::<&str>::try_from(&Value::from("a")), Ok(Some("a")));
+    assert_equal!(Option::<&str>::try_from(Value::Nil), Ok(None));
+    assert_equal!(
+        Option::<i64>::try_from(Value::from("a")),
+        Err(ValueError::Mismatch {
+            expected: ValueKind::Int,
+            actual: ValueKind::String
+        })
+    );
+}
+
+#[test]
+fn test_try_from_value_collect() {
+    let cell = list!(1u8, 2u8, 300);
+    let numbers = cell.iter().map(u64::try_from).collect::<Result<Vec<_>, _>>();
+    assert_equal!(numbers, Ok(vec![1, 2, 300]));
+    let bytes = cell.iter().map(u8::try_from).collect::<Result<Vec<_>, _>>();
+    assert_equal!(bytes.is_err(), true);
+}
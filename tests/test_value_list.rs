@@ -0,0 +1,97 @@
+#![allow(unused)]
+use ds::sexp::{pretty, read};
+use ds::*;
+use k9::assert_equal;
+
+#[test]
+fn test_list_macro_nested() {
+    let cell = list!("a", ["b", "c"]);
+    assert_equal!(cell.len(), 2);
+    assert_equal!(cell.values(), vec![Value::from("a"), Value::List(list!("b", "c"))]);
+
+    let cell = list!(["a", ["b", [33u8]]], []);
+    assert_equal!(cell.values(), vec![
+        Value::List(list!("a", ["b", [33u8]])),
+        Value::List(Cell::nil())
+    ]);
+    assert_equal!(list!().is_nil(), true);
+}
+
+#[test]
+fn test_value_list_display_and_debug() {
+    let value = Value::List(list!("a", ["b", 2u8], []));
+    assert_equal!(format!("{}", value), r#"("a" ("b" 2u8) ())"#);
+    assert_equal!(format!("{:?}", value), r#"("a" ("b" 2u8) ())"#);
+    assert_equal!(list!("head", ["nested"], "tail").to_string(), r#"("head" ("nested") "tail")"#);
+}
+
+#[test]
+fn test_value_list_round_trips_through_read() {
+    let text = list!("a", ["b", ["c", 7u8]], [], "d").to_string();
+    assert_equal!(read(&text).unwrap(), list!("a", ["b", ["c", 7u8]], [], "d"));
+}
+
+#[test]
+fn test_value_list_pretty_nested() {
+    let cell = list!("define", ["square", "x"], ["multiply", "x", "x"]);
+    assert_equal!(
+        pretty(&cell, 30),
+        "(\"define\"\n (\"square\" \"x\")\n (\"multiply\" \"x\" \"x\"))"
+    );
+    assert_equal!(
+        pretty(&cell, 20),
+        "(\"define\"\n (\"square\" \"x\")\n (\"multiply\"\n  \"x\"\n  \"x\"))"
+    );
+}
+
+#[test]
+fn test_value_list_eq() {
+    assert_equal!(list!("a", ["b"]) == list!("a", ["b"]), true);
+    assert_equal!(list!("a", ["b"]) == list!("a", ["c"]), false);
+    assert_equal!(list!("a", ["b"]) == list!("a", "b"), false);
+    assert_equal!(Value::List(Cell::nil()) == Value::Nil, false);
+}
+
+#[test]
+fn test_value_list_ord() {
+    assert_equal!(list!("a") < list!("a", "b"), true);
+    assert_equal!(list!("a", "c") > list!("a", "b"), true);
+    assert_equal!(Value::List(list!(1u8)) > Value::Int(i64::MAX), true);
+
+    let mut values = vec![
+        Value::List(list!("b")),
+        Value::List(list!("a", "z")),
+        Value::List(list!("a")),
+        Value::List(Cell::nil()),
+    ];
+    values.sort();
+    assert_equal!(values, vec![
+        Value::List(Cell::nil()),
+        Value::List(list!("a")),
+        Value::List(list!("a", "z")),
+        Value::List(list!("b")),
+    ]);
+}
+
+#[test]
+fn test_value_list_cons_car_cdr() {
+    let mut tail = list!(["c", "d"]);
+    let cell = cons(list!("a", "b"), &mut tail);
+    assert_equal!(cell.to_string(), r#"(("a" "b") ("c" "d"))"#);
+    assert_equal!(car(&cell), Value::List(list!("a", "b")));
+    assert_equal!(car(&cdr(&cell)), Value::List(list!("c", "d")));
+
+    if let Value::List(nested) = car(&cell) {
+        assert_equal!(car(&cdr(&nested)), Value::from("b"));
+    } else {
+        panic!("expected a nested list");
+    }
+}
+
+#[test]
+fn test_value_list_clone_is_deep() {
+    let cell = list!("a", ["b", "c"]);
+    let copy = cell.clone();
+    drop(cell);
+    assert_equal!(copy.to_string(), r#"("a" ("b" "c"))"#);
+}
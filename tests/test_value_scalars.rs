@@ -0,0 +1,144 @@
+#![allow(unused)]
+use ds::sexp::{read, ParseError, ParseErrorKind};
+use ds::*;
+use k9::assert_equal;
+
+#[test]
+fn test_value_from_scalars() {
+    assert_equal!(Value::from(1.5), Value::Float(1.5));
+    assert_equal!(Value::from(true), Value::Bool(true));
+    assert_equal!(Value::from('a'), Value::Char('a'));
+    assert_equal!(Cell::from(1.5).head(), Some(Value::Float(1.5)));
+    assert_equal!(Cell::from(false).head(), Some(Value::Bool(false)));
+    assert_equal!(Cell::from('z').head(), Some(Value::Char('z')));
+    assert_equal!(list!(1.5, true, 'a').values(), vec![
+        Value::Float(1.5),
+        Value::Bool(true),
+        Value::Char('a')
+    ]);
+}
+
+#[test]
+fn test_value_scalars_display() {
+    assert_equal!(Value::Float(1.5).to_string(), "1.5");
+    assert_equal!(Value::Bool(true).to_string(), "true");
+    assert_equal!(Value::Char('a').to_string(), "a");
+}
+
+#[test]
+fn test_value_scalars_debug() {
+    assert_equal!(format!("{:?}", Value::Float(42.0)), "42.0f64");
+    assert_equal!(format!("{:?}", Value::Float(-0.5)), "-0.5f64");
+    assert_equal!(format!("{:?}", Value::Float(f64::NAN)), "NaNf64");
+    assert_equal!(format!("{:?}", Value::Float(f64::NEG_INFINITY)), "-inff64");
+    assert_equal!(format!("{:?}", Value::Bool(false)), "false");
+    assert_equal!(format!("{:?}", Value::Char('a')), "'a'");
+    assert_equal!(format!("{:?}", Value::Char('\'')), r"'\''");
+    assert_equal!(list!(42u8, 1.5, true, 'a').to_string(), "(42u8 1.5f64 true 'a')");
+}
+
+#[test]
+fn test_value_float_total_order() {
+    let mut values = vec![
+        Value::Float(f64::NAN),
+        Value::Float(1.0),
+        Value::Float(0.0),
+        Value::Float(f64::INFINITY),
+        Value::Float(-0.0),
+        Value::Float(f64::NEG_INFINITY),
+        Value::Float(-f64::NAN),
+    ];
+    values.sort();
+    assert_equal!(
+        values.iter().map(|value| format!("{:?}", value)).collect::<Vec<_>>(),
+        vec!["NaNf64", "-inff64", "-0.0f64", "0.0f64", "1.0f64", "inff64", "NaNf64"]
+    );
+    assert_equal!(values[0] < values[1], true);
+    assert_equal!(values[6] > values[5], true);
+}
+
+#[test]
+fn test_value_float_eq() {
+    assert_equal!(Value::Float(f64::NAN) == Value::Float(f64::NAN), true);
+    assert_equal!(Value::Float(0.0) == Value::Float(-0.0), false);
+    assert_equal!(Value::Float(1.5) == Value::Float(1.5), true);
+    assert_equal!(list!(1.5, 2.5) == list!(1.5, 2.5), true);
+}
+
+#[test]
+fn test_value_order_across_variants() {
+    let mut values = vec![
+        Value::List(list!("a")),
+        Value::Char('a'),
+        Value::Bool(false),
+        Value::Float(-1.0),
+        Value::Int(-1),
+        Value::UInt(1),
+        Value::Byte(1),
+        Value::from("a"),
+        Value::Nil,
+    ];
+    values.sort();
+    assert_equal!(values, vec![
+        Value::Nil,
+        Value::from("a"),
+        Value::Byte(1),
+        Value::UInt(1),
+        Value::Int(-1),
+        Value::Float(-1.0),
+        Value::Bool(false),
+        Value::Char('a'),
+        Value::List(list!("a")),
+    ]);
+}
+
+#[test]
+fn test_value_scalars_in_tree() {
+    let mut tree = BinaryTree::nil();
+    for value in [2.5, f64::NAN, -1.0, 0.0] {
+        tree.insert(Value::Float(value));
+    }
+    assert_equal!(
+        tree.iter_inorder().values().map(|value| value.to_string()).collect::<Vec<_>>(),
+        vec!["-1", "0", "2.5", "NaN"]
+    );
+}
+
+#[test]
+fn test_read_scalars() {
+    let cell = read("(1.5f64 -2.0 1e3 NaNf64 -inff64 true false 'a' '\\n' '\\'' 'b)").unwrap();
+    assert_equal!(
+        cell.to_string(),
+        r#"(1.5f64 -2.0f64 1000.0f64 NaNf64 -inff64 true false 'a' '\n' '\'' (quote b))"#
+    );
+    assert_equal!(read("'x'").unwrap().values(), vec![Value::Char('x')]);
+    assert_equal!(read("'x").unwrap(), list!(quote, x));
+}
+
+#[test]
+fn test_read_scalars_round_trip() {
+    let text = list!(0.1, -0.0, 1e300, true, 'a', '"', '\\', '\u{1F600}').to_string();
+    assert_equal!(read(&text).unwrap(), list!(0.1, -0.0, 1e300, true, 'a', '"', '\\', '\u{1F600}'));
+    let nan = Cell::from(f64::NAN).to_string();
+    assert_equal!(read(&nan).unwrap().head().unwrap(), Value::Float(f64::NAN));
+}
+
+#[test]
+fn test_read_scalar_errors() {
+    assert_equal!(
+        read("(1.5.0)"),
+        Err(ParseError {
+            kind: ParseErrorKind::InvalidNumber("1.5.0".to_string()),
+            line: 1,
+            column: 2
+        })
+    );
+    assert_equal!(
+        read("(a '\\n)"),
+        Err(ParseError {
+            kind: ParseErrorKind::UnterminatedChar,
+            line: 1,
+            column: 4
+        })
+    );
+}
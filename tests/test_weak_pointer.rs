@@ -0,0 +1,180 @@
+#![allow(unused)]
+use ds::*;
+use k9::assert_equal;
+
+#[test]
+fn test_refcounter_strong_and_weak_counts() {
+    let mut counter = RefCounter::new();
+    assert_equal!(counter.strong(), 1);
+    assert_equal!(counter.weak(), 0);
+
+    counter.incr_weak();
+    counter.incr_weak();
+    counter.incr();
+    assert_equal!(counter.strong(), 2);
+    assert_equal!(counter.read(), 2);
+    assert_equal!(counter.weak(), 2);
+
+    counter.decr_weak();
+    assert_equal!(counter.weak(), 1);
+    assert_equal!(counter.strong(), 2);
+    counter.decr_weak();
+    counter.decr_weak();
+    assert_equal!(counter.weak(), 0);
+}
+
+#[test]
+fn test_refcounter_dealloc_with_weak_references() {
+    let mut counter = RefCounter::new();
+    let mut weak = counter.clone();
+    weak.incr_weak();
+
+    counter.dealloc();
+    assert_equal!(counter.is_null(), true);
+    assert_equal!(weak.is_released(), true);
+    assert_equal!(weak.strong(), 0);
+    assert_equal!(weak.weak(), 1);
+
+    weak.dealloc_weak();
+    assert_equal!(weak.is_null(), true);
+    assert_equal!(weak.read(), 0);
+}
+
+#[test]
+fn test_weak_pointer_upgrade() {
+    let mut value = UniquePointer::from(Value::from("string"));
+    let weak = value.downgrade();
+
+    assert_equal!(weak.is_null(), false);
+    assert_equal!(weak.addr(), value.addr());
+    assert_equal!(weak.strong_count(), 1);
+    assert_equal!(weak.weak_count(), 1);
+    assert_equal!(value.refs(), 1);
+
+    let upgraded = weak.upgrade().unwrap();
+    assert_equal!(upgraded.read(), Value::from("string"));
+    assert_equal!(upgraded.is_copy(), true);
+    assert_equal!(value.refs(), 2);
+    assert_equal!(weak.as_ref(), Some(&Value::from("string")));
+}
+
+#[test]
+fn test_weak_pointer_does_not_count_as_reference() {
+    let value = UniquePointer::from(Value::from("string"));
+    let weak = value.downgrade();
+    let clone = weak.clone();
+
+    assert_equal!(value.refs(), 1);
+    assert_equal!(weak.weak_count(), 2);
+    assert_equal!(clone.weak_count(), 2);
+    assert_equal!(clone.addr(), weak.addr());
+}
+
+#[test]
+fn test_weak_pointer_upgrade_after_dealloc() {
+    let mut value = UniquePointer::from(Value::from("string"));
+    let mut weak = value.downgrade();
+
+    value.dealloc(true);
+    assert_equal!(value.is_null(), true);
+    assert_equal!(weak.is_dangling(), true);
+    assert_equal!(weak.strong_count(), 0);
+    assert_equal!(weak.upgrade().is_none(), true);
+    assert_equal!(weak.as_ref(), None);
+
+    weak.dealloc();
+    assert_equal!(weak.is_null(), true);
+    assert_equal!(weak.is_dangling(), false);
+    assert_equal!(weak.weak_count(), 0);
+}
+
+#[test]
+fn test_weak_pointer_dealloc_releases_weak_reference() {
+    let value = UniquePointer::from(Value::from("string"));
+    let mut weak = value.downgrade();
+    let other = value.downgrade();
+
+    weak.dealloc();
+    assert_equal!(weak.is_null(), true);
+    assert_equal!(weak.upgrade().is_none(), true);
+    assert_equal!(other.weak_count(), 1);
+    assert_equal!(other.upgrade().map(|value| value.read()), Some(Value::from("string")));
+}
+
+#[test]
+fn test_weak_pointer_null() {
+    let weak = WeakPointer::<Value>::null();
+    assert_equal!(weak.is_null(), true);
+    assert_equal!(weak.is_dangling(), false);
+    assert_equal!(weak.upgrade().is_none(), true);
+    assert_equal!(weak.strong_count(), 0);
+    assert_equal!(weak.weak_count(), 0);
+
+    let null = UniquePointer::<Value>::null();
+    assert_equal!(null.downgrade().is_null(), true);
+}
+
+#[test]
+#[should_panic(expected = "not upgradable")]
+fn test_weak_pointer_inner_ref_panics_when_dangling() {
+    let mut value = UniquePointer::from(Value::from("string"));
+    let weak = value.downgrade();
+    value.dealloc(true);
+    weak.inner_ref();
+}
+
+#[test]
+fn test_node_parent_is_weak() {
+    let mut node = Node::new(Value::from("value"));
+    let mut left = Node::new(Value::from("left"));
+    let mut right = Node::new(Value::from("right"));
+
+    node.set_left(&mut left);
+    node.set_right(&mut right);
+
+    assert_equal!(left.parent.addr(), node.addr());
+    assert_equal!(left.parent.weak_count(), 2);
+    assert_equal!(left.parent.strong_count(), node.refs());
+    assert_equal!(left.parent().map(Node::value), Some(node.value()));
+
+    right.disconnect();
+    assert_equal!(right.parent.is_null(), true);
+    assert_equal!(left.parent.weak_count(), 1);
+    assert_equal!(node.right(), None);
+}
+
+#[test]
+fn test_node_set_left_releases_previous_parent() {
+    let mut node_a = Node::new(Value::from("A"));
+    let mut node_b = Node::new(Value::from("B"));
+    let mut node_c = Node::new(Value::from("C"));
+    let mut node_d = Node::new(Value::from("D"));
+
+    node_a.set_left(&mut node_c);
+    node_a.set_right(&mut node_d);
+    node_b.set_left(&mut node_c);
+
+    assert_equal!(node_c.parent_value(), Some(Value::from("B")));
+    assert_equal!(node_c.parent.weak_count(), 1);
+    assert_equal!(node_d.parent.weak_count(), 1);
+}
+
+#[test]
+fn test_node_parent_outlived_by_subtree() {
+    let mut tree = BinaryTree::new(Value::from("A"));
+    let node_b = tree.node(Value::from("B"));
+    tree.root_mut().unwrap().set_left(node_b);
+
+    let mut parent = node_b.parent.clone();
+    assert_equal!(parent.weak_count(), 2);
+    assert_equal!(parent.upgrade().is_some(), true);
+
+    drop(tree);
+    assert_equal!(parent.is_dangling(), true);
+    assert_equal!(parent.weak_count(), 1);
+    assert_equal!(parent.upgrade().is_none(), true);
+    assert_equal!(parent.as_ref().is_none(), true);
+
+    parent.dealloc();
+    assert_equal!(parent.is_null(), true);
+}